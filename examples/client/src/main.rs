@@ -0,0 +1,14 @@
+use named_tup::{tup, TupInto};
+
+// The tups of this crate can be converted to and from the ones of the namespaced crates it depends on.
+named_tup::tup_namespace!();
+
+fn main() {
+    let request = tup!(host: "example.com", port: 443, path: "/index.html");
+
+    let address = namespace::connect(request.without_path().into_tup());
+    assert_eq!(address, "example.com:443 (3 retries)");
+
+    // Will print example.com:443 (3 retries) /index.html
+    println!("{address} {}", request.path);
+}
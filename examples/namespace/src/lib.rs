@@ -0,0 +1,20 @@
+use named_tup::{tup_default, Tup};
+
+// Generates a tup type containing only the arguments used in this crate.
+named_tup::tup_namespace!();
+
+/// Formats the address to connect to.
+///
+/// ```
+/// use named_tup::{tup, TupInto};
+///
+/// let address = namespace::connect(tup!(host: "localhost").into_tup());
+/// assert_eq!(address, "localhost:8080 (3 retries)");
+/// ```
+#[tup_default]
+pub fn connect(options: Tup!(host: &'static str, port: u16 = 8080, retries: u8 = 3)) -> String {
+    format!(
+        "{}:{} ({} retries)",
+        options.host, options.port, options.retries
+    )
+}
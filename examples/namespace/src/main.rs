@@ -0,0 +1,9 @@
+use named_tup::{tup, TupInto};
+
+fn main() {
+    // The binary uses the tup type of the library.
+    let options = tup!(host: "localhost", retries: 5);
+
+    // Will print localhost:8080 (5 retries)
+    println!("{}", namespace::connect(options.into_tup()));
+}
@@ -0,0 +1,14 @@
+use named_tup::{tup, Tup, TupInto};
+
+#[test]
+fn defaults() {
+    let options: Tup!(host: &'static str, retries: u8) = tup!(host: "localhost", retries: 5);
+    assert_eq!(
+        namespace::connect(options.into_tup()),
+        "localhost:8080 (5 retries)"
+    );
+    assert_eq!(
+        namespace::connect(tup!(host: "localhost", port: 80).into_tup()),
+        "localhost:80 (3 retries)"
+    );
+}
@@ -0,0 +1,18 @@
+use named_tup::{tup, tup_default, Tup};
+
+#[test]
+#[tup_default]
+fn defaults() {
+    let json = serde_json::to_string(&tup!(host: "localhost", retries: 5)).unwrap();
+    assert_eq!(json, r#"{"host":"localhost","retries":5}"#);
+
+    let options: Tup!(host: String, port: u16 = 8080, retries: u8 = 3) =
+        serde_json::from_str(r#"{"host":"localhost","retries":5}"#).unwrap();
+    assert_eq!(
+        options,
+        tup!(host: "localhost".to_string(), port: 8080, retries: 5)
+    );
+
+    let missing = serde_json::from_str::<Tup!(host: String, port: u16)>(r#"{"host":"localhost"}"#);
+    assert!(missing.is_err());
+}
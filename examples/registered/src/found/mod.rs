@@ -0,0 +1,6 @@
+use named_tup::{tup, Tup};
+
+// Found by scanning the `sources` of named-tup.toml.
+pub fn colour() -> Tup!(colour: &'static str) {
+    tup!(colour: "red")
+}
@@ -0,0 +1,21 @@
+use named_tup::tup;
+
+// Only the files in src/found are scanned, so the arguments used here have to be registered.
+mod found;
+
+fn main() {
+    // Registered in the `arguments` list of named-tup.toml.
+    let body = tup!(wheels: 4, doors: 5);
+
+    // Registered in the NAMED_TUP_ARGUMENTS variable of .cargo/config.toml.
+    let inside = tup!(seats: 5, trunk: true);
+
+    let car = body + inside + found::colour();
+    assert_eq!(
+        car.field_names(),
+        ["colour", "doors", "seats", "trunk", "wheels"]
+    );
+
+    // Will print (colour: "red", doors: 5, seats: 5, trunk: true, wheels: 4)
+    println!("{car}");
+}
@@ -0,0 +1,10 @@
+use named_tup::{tup, TupInto};
+
+fn main() {
+    // The shared tups of named-tup can be converted to the ones of namespaced crates.
+    let address = namespace::connect(tup!(host: "localhost", retries: 1).into_tup());
+    assert_eq!(address, "localhost:8080 (1 retries)");
+
+    // Will print localhost:8080 (1 retries)
+    println!("{address}");
+}
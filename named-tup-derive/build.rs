@@ -1,15 +1,22 @@
 extern crate core;
 
-use std::collections::HashSet;
-use std::path::Path;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
 use std::{env, fs};
 
 use inwelling::Opts;
 
+use report::{Identifiers, Origin};
+
+mod namespaces;
+mod report;
 mod tup_finder;
 
+const CONFIG_FILE_NAME: &str = "named-tup.toml";
+
 pub fn main() {
-    let mut all_identifiers = HashSet::new();
+    let mut all_identifiers = Identifiers::default();
+    let mut manifest_dirs = vec![];
 
     // Docs.rs does not seem to like inwelling so we will just not use it.
     if env::var("DOCS_RS").is_err() {
@@ -20,49 +27,222 @@ pub fn main() {
         });
 
         downstream.packages.into_iter().for_each(|package| {
+            manifest_dirs.push(package.manifest.parent().unwrap().to_path_buf());
+            // Namespaced packages are found by find_namespaces and get their own Tup struct.
+            if package.metadata.get("namespace").and_then(|n| n.as_bool()) == Some(true) {
+                return;
+            }
             if let Some(idents) = package.metadata.as_table().and_then(|t| t.get("arguments")) {
-                if let Some(a) = idents.as_array() {
-                    for ident in a {
-                        if let Some(ident) = ident.as_str() {
-                            all_identifiers.insert(ident.into());
-                        } else {
-                            panic!("Expected to find an array of idents in the Cargo.toml file.")
-                        }
-                    }
-                } else {
-                    panic!("Expected to find an array of idents in the Cargo.toml file.")
-                }
+                add_arguments(idents, &mut all_identifiers, || Origin::Arguments {
+                    package: package.name.clone(),
+                });
             } else {
                 package.rs_paths.unwrap().into_iter().for_each(|rs_path| {
                     println!("cargo:rerun-if-changed={}", rs_path.to_str().unwrap());
-                    tup_finder::get_all_identifiers(&rs_path, &mut all_identifiers);
+                    add_source_identifiers(&rs_path, Some(&package.name), &mut all_identifiers);
                 })
             }
         });
     }
 
+    // OUT_DIR is `<target>/[<triple>/]<profile>/build/<package>/out`.
+    let out_dir = &env::var("OUT_DIR").unwrap();
+    let build_dir = Path::new(out_dir).ancestors().nth(2).unwrap();
+
+    add_env_identifiers(&mut all_identifiers);
+    add_config_identifiers(&manifest_dirs, build_dir, &mut all_identifiers);
+
     // Add doc test identifiers
     if cfg!(feature = "add_dev_idents") {
-        all_identifiers.extend(
-            include! {"dev_idents.in"}
-                .into_iter()
-                .map(|s| s.to_string()),
-        )
+        include! {"dev_idents.in"}
+            .into_iter()
+            .for_each(|s| all_identifiers.insert(s.to_string(), Origin::DevIdents))
     }
 
-    let mut all_identifiers: Vec<String> = all_identifiers.into_iter().collect();
-    all_identifiers.sort();
+    println!("cargo:rerun-if-env-changed=NAMED_TUP_REPORT");
+    let report_path = Path::new(out_dir).join("identifiers.json");
+    if env::var_os("NAMED_TUP_REPORT").is_some() {
+        fs::write(&report_path, all_identifiers.to_json())
+            .unwrap_or_else(|_| panic!("Could not write to file at: {report_path:?}"));
+        all_identifiers.print_summary(&report_path);
+    } else if report_path.is_file() {
+        // Do not leave the report of a previous build behind.
+        fs::remove_file(&report_path)
+            .unwrap_or_else(|_| panic!("Could not remove file at: {report_path:?}"));
+    }
 
-    let out_dir = &env::var("OUT_DIR").unwrap();
     let gen_path = Path::new(out_dir).join("identifiers.in");
+    let new_file_contents = format!("&{:?}", all_identifiers.names());
+    write_if_changed(&gen_path, &new_file_contents);
+
+    // The workspace containing the build directory is usually the one being built.
+    let workspace = workspace_root(build_dir).map(Path::to_path_buf);
+    let namespaces = namespaces::find_namespaces(manifest_dirs.into_iter().chain(workspace));
+    let gen_path = Path::new(out_dir).join("namespaces.in");
+    write_if_changed(&gen_path, &namespaces::to_string(&namespaces));
+}
 
-    let new_file_contents = format!("&{all_identifiers:?}");
-    if should_rewrite_file(&gen_path, &new_file_contents) {
-        fs::write(&gen_path, new_file_contents)
+fn write_if_changed(gen_path: &Path, new_file_contents: &str) {
+    if should_rewrite_file(gen_path, new_file_contents) {
+        fs::write(gen_path, new_file_contents)
             .unwrap_or_else(|_| panic!("Could not write to file at: {gen_path:?}"));
     }
 }
 
+fn add_arguments(
+    idents: &toml::Value,
+    all_identifiers: &mut Identifiers,
+    origin: impl Fn() -> Origin,
+) {
+    if let Some(a) = idents.as_array() {
+        for ident in a {
+            if let Some(ident) = ident.as_str() {
+                all_identifiers.insert(ident.into(), origin());
+            } else {
+                panic!("Expected to find an array of idents in the arguments list.")
+            }
+        }
+    } else {
+        panic!("Expected to find an array of idents in the arguments list.")
+    }
+}
+
+/// Adds the comma or whitespace separated identifiers of the `NAMED_TUP_ARGUMENTS` variable.
+fn add_env_identifiers(all_identifiers: &mut Identifiers) {
+    println!("cargo:rerun-if-env-changed=NAMED_TUP_ARGUMENTS");
+    if let Ok(idents) = env::var("NAMED_TUP_ARGUMENTS") {
+        idents
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|ident| !ident.is_empty())
+            .for_each(|ident| all_identifiers.insert(ident.into(), Origin::Environment));
+    }
+}
+
+/// Adds the identifiers of the config file given by the `NAMED_TUP_CONFIG` variable, or else of
+/// the `named-tup.toml` files found by [`find_config_files`].
+fn add_config_identifiers(
+    manifest_dirs: &[PathBuf],
+    build_dir: &Path,
+    all_identifiers: &mut Identifiers,
+) {
+    println!("cargo:rerun-if-env-changed=NAMED_TUP_CONFIG");
+    // A given config file is watched even if it does not exist yet so that creating it is picked
+    // up. The discovered ones are only watched once they exist, as cargo reruns build scripts that
+    // watch a missing file on every build, which would recompile every tup user each time.
+    let config_paths = match env::var_os("NAMED_TUP_CONFIG") {
+        Some(path) => BTreeSet::from([PathBuf::from(path)]),
+        None => find_config_files(manifest_dirs, build_dir),
+    };
+    for config_path in config_paths {
+        add_config_file_identifiers(&config_path, all_identifiers);
+    }
+}
+
+fn add_config_file_identifiers(config_path: &Path, all_identifiers: &mut Identifiers) {
+    println!("cargo:rerun-if-changed={}", config_path.to_str().unwrap());
+
+    let config = match fs::read_to_string(config_path) {
+        Ok(config) => config,
+        Err(err) => {
+            println!(
+                "cargo:warning=named-tup could not read the config file at {}: {err}",
+                config_path.display()
+            );
+            return;
+        }
+    };
+    let config = config
+        .parse::<toml::Table>()
+        .unwrap_or_else(|e| panic!("Could not parse the config file at {config_path:?}: {e}"));
+
+    if let Some(idents) = config.get("arguments") {
+        add_arguments(idents, all_identifiers, || Origin::Config {
+            file: config_path.to_path_buf(),
+        });
+    }
+
+    if let Some(sources) = config.get("sources") {
+        let config_dir = config_path.parent().unwrap();
+        let sources = sources
+            .as_array()
+            .expect("Expected to find an array of source directories in the config file.");
+        for source in sources {
+            let source = source
+                .as_str()
+                .expect("Expected to find an array of source directories in the config file.");
+            let mut rs_paths = vec![];
+            tup_finder::scan_rs_paths(&config_dir.join(source), &mut rs_paths);
+            rs_paths.into_iter().for_each(|rs_path| {
+                println!("cargo:rerun-if-changed={}", rs_path.to_str().unwrap());
+                add_source_identifiers(&rs_path, None, all_identifiers);
+            })
+        }
+    }
+}
+
+fn add_source_identifiers(
+    rs_path: &Path,
+    package: Option<&String>,
+    all_identifiers: &mut Identifiers,
+) {
+    let mut found = vec![];
+    if let Err(err) = tup_finder::get_all_identifiers(rs_path, &mut found) {
+        println!(
+            "cargo:warning=named-tup could not parse {}: {err}",
+            rs_path.display()
+        );
+    }
+    for (ident, line) in found {
+        let origin = Origin::Source {
+            package: package.cloned(),
+            file: rs_path.to_path_buf(),
+            line,
+        };
+        all_identifiers.insert(ident, origin);
+    }
+}
+
+/// Looks for a `named-tup.toml` file in the build directory and in the directory of each crate
+/// registered with inwelling, then in their parents up to the root of their workspace. The build
+/// directory is usually inside the workspace, which finds the file of crates without a build
+/// script.
+fn find_config_files(manifest_dirs: &[PathBuf], build_dir: &Path) -> BTreeSet<PathBuf> {
+    let mut config_paths = BTreeSet::new();
+    for dir in manifest_dirs
+        .iter()
+        .map(PathBuf::as_path)
+        .chain([build_dir])
+    {
+        let Some(root) = workspace_root(dir) else {
+            continue;
+        };
+        let config_path = dir
+            .ancestors()
+            .take_while(|ancestor| ancestor.starts_with(root))
+            .map(|ancestor| ancestor.join(CONFIG_FILE_NAME))
+            .find(|path| path.is_file());
+        config_paths.extend(config_path);
+    }
+    config_paths
+}
+
+/// The closest directory above `dir` whose manifest has a `[workspace]` section, or else the
+/// closest one with a manifest, if `dir` is inside a package at all.
+fn workspace_root(dir: &Path) -> Option<&Path> {
+    let mut package_root = None;
+    for ancestor in dir.ancestors() {
+        let Ok(manifest) = fs::read_to_string(ancestor.join("Cargo.toml")) else {
+            continue;
+        };
+        let manifest = manifest.parse::<toml::Table>();
+        if manifest.is_ok_and(|manifest| manifest.contains_key("workspace")) {
+            return Some(ancestor);
+        }
+        package_root.get_or_insert(ancestor);
+    }
+    package_root
+}
+
 fn should_rewrite_file(gen_path: &Path, new_file_contents: &str) -> bool {
     if !gen_path.is_file() {
         return true;
@@ -0,0 +1,294 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::tup_finder;
+
+/// A crate that sets `namespace = true` in its `[package.metadata.inwelling.named-tup-derive]`,
+/// which gets its own Tup struct from tup_namespace!().
+pub struct Namespace {
+    pub manifest_dir: PathBuf,
+    /// The name of the crate, used to refer to its Tup struct from its tests and examples.
+    pub krate: String,
+    /// The identifiers of the crate in sorted order.
+    pub identifiers: Vec<String>,
+    /// The name the crate refers to each namespaced crate it depends on with, and its directory.
+    pub dependencies: Vec<(String, PathBuf)>,
+}
+
+/// Finds the namespaced crates among the packages in `dirs`, the members of the workspaces in
+/// `dirs` and all of their path dependencies.
+///
+/// Namespaced crates do not register themselves with inwelling, so they are found through the
+/// manifests alone, which does not depend on the order in which build scripts run.
+pub fn find_namespaces(dirs: impl IntoIterator<Item = PathBuf>) -> Vec<Namespace> {
+    let mut manifests = BTreeMap::new();
+    let mut pending: Vec<PathBuf> = dirs.into_iter().map(|dir| normalize(&dir)).collect();
+    while let Some(dir) = pending.pop() {
+        if manifests.contains_key(&dir) {
+            continue;
+        }
+        let Some(manifest) = read_manifest(&dir) else {
+            continue;
+        };
+        pending.extend(workspace_members(&dir, &manifest));
+        for table in ["dependencies", "dev-dependencies", "build-dependencies"] {
+            let Some(dependencies) = manifest.get(table).and_then(|d| d.as_table()) else {
+                continue;
+            };
+            pending.extend(
+                dependencies
+                    .iter()
+                    .filter_map(|(name, dependency)| dependency_dir(&dir, name, dependency)),
+            );
+        }
+        manifests.insert(dir, manifest);
+    }
+
+    manifests
+        .iter()
+        .filter_map(|(manifest_dir, manifest)| {
+            let metadata = namespace_metadata(manifest)?;
+            Some(Namespace {
+                manifest_dir: manifest_dir.clone(),
+                krate: crate_name(manifest)?,
+                identifiers: find_identifiers(manifest_dir, metadata),
+                dependencies: namespaced_dependencies(manifest_dir, manifest, &manifests),
+            })
+        })
+        .collect()
+}
+
+/// Generates the list of namespaces read by the procedural macros.
+pub fn to_string(namespaces: &[Namespace]) -> String {
+    let namespaces: Vec<String> = namespaces
+        .iter()
+        .map(|namespace| {
+            let dependencies: Vec<String> = namespace
+                .dependencies
+                .iter()
+                .filter_map(|(krate, dependency_dir)| {
+                    let dependency = namespaces
+                        .iter()
+                        .find(|n| &n.manifest_dir == dependency_dir)?;
+                    Some(format!("({krate:?}, &{:?})", dependency.identifiers))
+                })
+                .collect();
+            format!(
+                "Namespace {{ manifest_dir: {:?}, krate: {:?}, identifiers: &{:?}, dependencies: &[{}] }}",
+                namespace.manifest_dir.to_str().unwrap(),
+                namespace.krate,
+                namespace.identifiers,
+                dependencies.join(", ")
+            )
+        })
+        .collect();
+    format!("&[{}]", namespaces.join(", "))
+}
+
+fn read_manifest(dir: &Path) -> Option<toml::Table> {
+    let manifest_path = dir.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path).ok()?;
+    println!("cargo:rerun-if-changed={}", manifest_path.to_str().unwrap());
+    manifest.parse::<toml::Table>().ok()
+}
+
+/// Removes the `.` and `..` components of a path the way cargo does, so that the directories
+/// match the `CARGO_MANIFEST_DIR` given to the crates.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// The packages of a workspace, given by its `members` list, which may use `*` and `?`
+/// wildcards, without the ones in its `exclude` list.
+fn workspace_members(root: &Path, manifest: &toml::Table) -> Vec<PathBuf> {
+    let Some(workspace) = manifest.get("workspace") else {
+        return vec![];
+    };
+    let paths = |key: &str| -> Vec<&str> {
+        workspace
+            .get(key)
+            .and_then(|paths| paths.as_array())
+            .map_or(vec![], |paths| {
+                paths.iter().filter_map(|path| path.as_str()).collect()
+            })
+    };
+    let excluded: Vec<PathBuf> = paths("exclude")
+        .into_iter()
+        .map(|path| normalize(&root.join(path)))
+        .collect();
+
+    let mut members = vec![];
+    for pattern in paths("members") {
+        let mut dirs = vec![root.to_path_buf()];
+        for component in pattern.split('/') {
+            if !component.contains(['*', '?']) {
+                dirs.iter_mut().for_each(|dir| dir.push(component));
+                continue;
+            }
+            dirs = dirs
+                .iter()
+                .filter_map(|dir| fs::read_dir(dir).ok())
+                .flatten()
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .filter(|entry| {
+                    let name = entry.file_name();
+                    name.to_str()
+                        .is_some_and(|name| matches_wildcard(component, name))
+                })
+                .map(|entry| entry.path())
+                .collect();
+        }
+        members.extend(dirs.iter().map(|dir| normalize(dir)));
+    }
+    members.retain(|member| !excluded.iter().any(|e| member.starts_with(e)));
+    members
+}
+
+/// Whether `name` matches `pattern`, in which `*` matches any sequence of characters and `?`
+/// matches a single character.
+fn matches_wildcard(pattern: &str, name: &str) -> bool {
+    match pattern.chars().next() {
+        None => name.is_empty(),
+        Some('*') => {
+            let rest = &pattern[1..];
+            name.char_indices()
+                .map(|(index, _)| index)
+                .chain([name.len()])
+                .any(|index| matches_wildcard(rest, &name[index..]))
+        }
+        Some(first) => {
+            let Some(next) = name.chars().next() else {
+                return false;
+            };
+            (first == '?' || first == next)
+                && matches_wildcard(&pattern[first.len_utf8()..], &name[next.len_utf8()..])
+        }
+    }
+}
+
+/// The directory of a path dependency, including the ones inherited from the workspace.
+fn dependency_dir(manifest_dir: &Path, name: &str, dependency: &toml::Value) -> Option<PathBuf> {
+    if let Some(path) = dependency.get("path").and_then(|p| p.as_str()) {
+        return Some(normalize(&manifest_dir.join(path)));
+    }
+    if dependency.get("workspace").and_then(|w| w.as_bool()) != Some(true) {
+        return None;
+    }
+    manifest_dir.ancestors().skip(1).find_map(|dir| {
+        let manifest = fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+        let path = manifest
+            .parse::<toml::Table>()
+            .ok()?
+            .get("workspace")?
+            .get("dependencies")?
+            .get(name)?
+            .get("path")?
+            .as_str()?
+            .to_string();
+        Some(normalize(&dir.join(path)))
+    })
+}
+
+/// The `[package.metadata.inwelling.named-tup-derive]` of a manifest if it sets `namespace = true`.
+fn namespace_metadata(manifest: &toml::Table) -> Option<&toml::Value> {
+    let metadata = manifest
+        .get("package")?
+        .get("metadata")?
+        .get("inwelling")?
+        .get("named-tup-derive")?;
+    match metadata.get("namespace").and_then(|n| n.as_bool()) {
+        Some(true) => Some(metadata),
+        _ => None,
+    }
+}
+
+/// The name of the package's library, its binary when it has none.
+fn crate_name(manifest: &toml::Table) -> Option<String> {
+    let package = manifest.get("package")?.get("name")?.as_str()?;
+    let name = manifest
+        .get("lib")
+        .and_then(|lib| lib.get("name"))
+        .and_then(|name| name.as_str())
+        .unwrap_or(package);
+    Some(name.replace('-', "_"))
+}
+
+/// The namespaced path dependencies of a package along with the name it refers to them with.
+/// Optional dependencies are skipped as they might not be enabled.
+fn namespaced_dependencies(
+    manifest_dir: &Path,
+    manifest: &toml::Table,
+    manifests: &BTreeMap<PathBuf, toml::Table>,
+) -> Vec<(String, PathBuf)> {
+    let Some(dependencies) = manifest.get("dependencies").and_then(|d| d.as_table()) else {
+        return vec![];
+    };
+    dependencies
+        .iter()
+        .filter(|(_, dependency)| {
+            dependency.get("optional").and_then(|o| o.as_bool()) != Some(true)
+        })
+        .filter_map(|(name, dependency)| {
+            let dependency_dir = dependency_dir(manifest_dir, name, dependency)?;
+            let dependency_manifest = manifests.get(&dependency_dir)?;
+            namespace_metadata(dependency_manifest)?;
+            let krate = match dependency.get("package") {
+                Some(_) => name.replace('-', "_"),
+                None => crate_name(dependency_manifest)?,
+            };
+            Some((krate, dependency_dir))
+        })
+        .collect()
+}
+
+/// The identifiers of a namespaced crate, given by its `arguments` list or found in the source
+/// files of its `src`, `examples`, `tests` and `benches` directories, which are then watched.
+fn find_identifiers(manifest_dir: &Path, metadata: &toml::Value) -> Vec<String> {
+    let mut identifiers = BTreeSet::new();
+    if let Some(arguments) = metadata.get("arguments") {
+        let arguments = arguments
+            .as_array()
+            .expect("Expected to find an array of idents in the arguments list.");
+        for argument in arguments {
+            let argument = argument
+                .as_str()
+                .expect("Expected to find an array of idents in the arguments list.");
+            identifiers.insert(argument.to_string());
+        }
+        return identifiers.into_iter().collect();
+    }
+
+    let mut rs_paths = vec![];
+    for dir in ["src", "examples", "tests", "benches"] {
+        let dir = manifest_dir.join(dir);
+        // Only existing directories are watched, as cargo reruns build scripts that watch a
+        // missing path on every build.
+        if dir.is_dir() {
+            println!("cargo:rerun-if-changed={}", dir.to_str().unwrap());
+            tup_finder::scan_rs_paths(&dir, &mut rs_paths);
+        }
+    }
+    for rs_path in rs_paths {
+        let mut found = vec![];
+        if let Err(err) = tup_finder::get_all_identifiers(&rs_path, &mut found) {
+            println!(
+                "cargo:warning=named-tup could not parse {}: {err}",
+                rs_path.display()
+            );
+        }
+        identifiers.extend(found.into_iter().map(|(ident, _)| ident));
+    }
+    identifiers.into_iter().collect()
+}
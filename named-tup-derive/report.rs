@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+/// Where an identifier was found.
+pub enum Origin {
+    /// A tup!/Tup! invocation in a source file, either of a downstream package or one of the
+    /// `sources` of the config file.
+    Source {
+        package: Option<String>,
+        file: PathBuf,
+        line: usize,
+    },
+    /// The `arguments` list of a downstream package's metadata.
+    Arguments { package: String },
+    /// The `arguments` list of the config file.
+    Config { file: PathBuf },
+    /// The `NAMED_TUP_ARGUMENTS` environment variable.
+    Environment,
+    /// The identifiers used by the doc tests of this crate.
+    DevIdents,
+}
+
+impl Display for Origin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Origin::Source {
+                package: Some(package),
+                file,
+                line,
+            } => write!(f, "{package} at {}:{line}", file.display()),
+            Origin::Source {
+                package: None,
+                file,
+                line,
+            } => write!(f, "{}:{line}", file.display()),
+            Origin::Arguments { package } => write!(f, "{package} arguments list"),
+            Origin::Config { file } => write!(f, "{} arguments list", file.display()),
+            Origin::Environment => f.write_str("NAMED_TUP_ARGUMENTS"),
+            Origin::DevIdents => f.write_str("dev idents"),
+        }
+    }
+}
+
+impl Origin {
+    fn to_json(&self) -> String {
+        match self {
+            Origin::Source {
+                package,
+                file,
+                line,
+            } => format!(
+                r#"{{"kind": "source", "package": {}, "file": {}, "line": {line}}}"#,
+                package.as_deref().map_or("null".into(), json_string),
+                json_path(file)
+            ),
+            Origin::Arguments { package } => format!(
+                r#"{{"kind": "arguments", "package": {}}}"#,
+                json_string(package)
+            ),
+            Origin::Config { file } => {
+                format!(r#"{{"kind": "config", "file": {}}}"#, json_path(file))
+            }
+            Origin::Environment => r#"{"kind": "environment"}"#.into(),
+            Origin::DevIdents => r#"{"kind": "dev_idents"}"#.into(),
+        }
+    }
+}
+
+/// All the identifiers that were found along with where they were found.
+#[derive(Default)]
+pub struct Identifiers(BTreeMap<String, Vec<Origin>>);
+
+impl Identifiers {
+    pub fn insert(&mut self, ident: String, origin: Origin) {
+        self.0.entry(ident).or_default().push(origin);
+    }
+
+    /// Returns the identifiers in sorted order.
+    pub fn names(&self) -> Vec<String> {
+        self.0.keys().cloned().collect()
+    }
+
+    /// Prints a summary of the identifiers as cargo warnings.
+    pub fn print_summary(&self, report_path: &Path) {
+        println!(
+            "cargo:warning=named-tup found {} identifiers, the full report is at {}",
+            self.0.len(),
+            report_path.display()
+        );
+        for (ident, origins) in &self.0 {
+            let first = origins
+                .iter()
+                .take(3)
+                .map(Origin::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            let more = match origins.len() {
+                0..=3 => String::new(),
+                len => format!(" and {} more", len - 3),
+            };
+            println!("cargo:warning={ident}: {first}{more}");
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let entries: Vec<String> = self
+            .0
+            .iter()
+            .map(|(ident, origins)| {
+                let origins: Vec<String> = origins.iter().map(Origin::to_json).collect();
+                format!(
+                    "  {}: [\n    {}\n  ]",
+                    json_string(ident),
+                    origins.join(",\n    ")
+                )
+            })
+            .collect();
+        format!("{{\n{}\n}}\n", entries.join(",\n"))
+    }
+}
+
+fn json_path(path: &Path) -> String {
+    json_string(&path.to_string_lossy())
+}
+
+fn json_string(s: &str) -> String {
+    let mut result = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
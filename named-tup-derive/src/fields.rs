@@ -0,0 +1,44 @@
+use proc_macro2::{Ident, Span, TokenStream};
+
+/// Generates a marker type for each identifier, `krate` being the path to named-tup.
+pub fn to_token_stream(identifiers: &[&str], krate: TokenStream) -> TokenStream {
+    let fields: Vec<Ident> = identifiers
+        .iter()
+        .map(|i| Ident::new(i, Span::call_site()))
+        .collect();
+    let docs = identifiers
+        .iter()
+        .map(|i| format!("Marker type for the `{i}` argument."));
+
+    let mut expanded = quote! {
+        #(
+            #[doc = #docs]
+            #[allow(non_camel_case_types)]
+            #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+            pub struct #fields;
+        )*
+    };
+
+    if cfg!(feature = "hlist") {
+        // The identifiers are sorted so the index of each marker gives the order of the arguments.
+        let width = usize::BITS - (identifiers.len().max(2) - 1).leading_zeros();
+        let indices = (0..identifiers.len()).map(|index| {
+            (0..width).fold(quote! {#krate::hlist::BEnd}, |rest, bit| {
+                match index >> bit & 1 {
+                    0 => quote! {#krate::hlist::B0<#rest>},
+                    _ => quote! {#krate::hlist::B1<#rest>},
+                }
+            })
+        });
+        expanded.extend(quote! {
+            #(
+                impl #krate::hlist::FieldMarker for #fields {
+                    type Index = #indices;
+                    const NAME: &'static str = stringify!(#fields);
+                }
+            )*
+        });
+    }
+
+    expanded
+}
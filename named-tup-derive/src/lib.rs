@@ -7,17 +7,22 @@ extern crate syn;
 use proc_macro::TokenStream;
 use std::env;
 
+use syn::parse::ParseStream;
 use syn::visit_mut::VisitMut;
-use syn::{parse_macro_input, Item};
+use syn::{parse_macro_input, Item, Visibility};
 
 use crate::tup_default::TupDefaultReplace;
 use crate::tup_invocation::{TupElementInvocation, TupTypeInvocation};
+use crate::tup_type::TupTypeAliases;
 
+mod fields;
+mod namespace;
 mod sealed;
 mod tup_default;
 mod tup_element;
 mod tup_invocation;
 mod tup_struct;
+mod tup_type;
 
 const IDENTIFIERS: &[&str] = include!(concat!(env!("OUT_DIR"), "/identifiers.in"));
 
@@ -31,10 +36,21 @@ pub fn sealed_trait_builder(_input: TokenStream) -> TokenStream {
     TokenStream::from(sealed::to_token_stream())
 }
 
+#[proc_macro]
+pub fn field_markers_builder(_input: TokenStream) -> TokenStream {
+    TokenStream::from(fields::to_token_stream(IDENTIFIERS, quote! {crate}))
+}
+
+#[proc_macro]
+pub fn tup_namespace(_input: TokenStream) -> TokenStream {
+    TokenStream::from(namespace::to_token_stream())
+}
+
 #[proc_macro]
 pub fn tup(input: TokenStream) -> TokenStream {
     if input.is_empty() {
-        return quote! {named_tup::__private::Tup::default()}.into();
+        let tup_path = namespace::tup_path();
+        return quote! {#tup_path::default()}.into();
     }
     let input = parse_macro_input!(input as TupElementInvocation);
     TokenStream::from(input.into_token_stream())
@@ -47,15 +63,50 @@ pub fn Tup(input: TokenStream) -> TokenStream {
     TokenStream::from(input.into_token_stream())
 }
 
+#[cfg(feature = "hlist")]
+#[proc_macro]
+pub fn htup(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as TupElementInvocation);
+    TokenStream::from(input.into_hlist_token_stream())
+}
+
+#[cfg(feature = "hlist")]
+#[proc_macro]
+#[allow(non_snake_case)]
+pub fn HTup(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as TupTypeInvocation);
+    TokenStream::from(input.into_hlist_token_stream())
+}
+
 #[proc_macro_attribute]
-pub fn tup_default(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn tup_default(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let exported = parse_macro_input!(attr with parse_exported);
     let mut syntax_tree = parse_macro_input!(item as Item);
-    let mut replace = TupDefaultReplace::default();
+    let mut replace = TupDefaultReplace::new(&syntax_tree);
+    replace.exported = exported;
     replace.visit_item_mut(&mut syntax_tree);
-    let struct_invocations = replace.struct_invocations;
+    let struct_invocations = replace.into_token_stream();
     let expanded = quote! {
         #struct_invocations
         #syntax_tree
     };
     expanded.into()
 }
+
+/// Parses the visibility given to the default structs by `#[tup_default(pub)]`.
+fn parse_exported(input: ParseStream) -> syn::Result<Visibility> {
+    let visibility = input.parse()?;
+    if !input.is_empty() {
+        return Err(input.error(
+            "Expected a visibility such as `pub` or `pub(crate)`, which is given to the structs of \
+             the defaults.",
+        ));
+    }
+    Ok(visibility)
+}
+
+#[proc_macro]
+pub fn tup_type(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as TupTypeAliases);
+    TokenStream::from(input.into_token_stream())
+}
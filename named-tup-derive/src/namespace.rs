@@ -0,0 +1,302 @@
+use std::env;
+use std::path::Path;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::ToTokens;
+
+use crate::tup_struct::{Conversion, TupInfo};
+use crate::IDENTIFIERS;
+
+/// A crate that sets `namespace = true` in its `[package.metadata.inwelling.named-tup-derive]`.
+///
+/// The namespaces are found by the build script among the members of the workspace being built
+/// and their path dependencies.
+struct Namespace {
+    manifest_dir: &'static str,
+    /// The name of the crate, used to refer to its Tup struct from its tests and examples.
+    krate: &'static str,
+    identifiers: &'static [&'static str],
+    /// The name and identifiers of each namespaced crate it depends on.
+    dependencies: &'static [(&'static str, &'static [&'static str])],
+}
+
+const NAMESPACES: &[Namespace] = include!(concat!(env!("OUT_DIR"), "/namespaces.in"));
+
+/// Returns the namespace of the crate currently being compiled if it has opted into one.
+fn current() -> Option<&'static Namespace> {
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR")?;
+    NAMESPACES
+        .iter()
+        .find(|namespace| Path::new(namespace.manifest_dir) == manifest_dir)
+}
+
+/// The identifiers that tup!/Tup! invocations of the current crate may use.
+pub fn identifiers() -> &'static [&'static str] {
+    current().map_or(IDENTIFIERS, |namespace| namespace.identifiers)
+}
+
+/// The path to the module containing the field marker types used by the current crate.
+pub fn markers_path() -> TokenStream {
+    match current() {
+        Some(namespace) => {
+            let krate = Ident::new(namespace.krate, Span::call_site());
+            quote! {::#krate::__named_tup::fields}
+        }
+        None => quote! {named_tup::fields},
+    }
+}
+
+/// The path to the Tup struct used by the current crate.
+pub fn tup_path() -> TokenStream {
+    match current() {
+        Some(namespace) => {
+            let krate = Ident::new(namespace.krate, Span::call_site());
+            quote! {::#krate::__named_tup::Tup}
+        }
+        None => quote! {named_tup::__private::Tup},
+    }
+}
+
+/// A Tup struct that a namespace can be converted to and from.
+struct TupSide<'a> {
+    identifiers: &'a [&'a str],
+    tup: TokenStream,
+    markers: TokenStream,
+}
+
+impl<'a> TupSide<'a> {
+    fn global() -> Self {
+        TupSide {
+            identifiers: IDENTIFIERS,
+            tup: quote! {named_tup::__private::Tup},
+            markers: quote! {named_tup::fields},
+        }
+    }
+
+    fn namespace(identifiers: &'a [&'a str], krate: TokenStream) -> Self {
+        TupSide {
+            identifiers,
+            tup: quote! {#krate::__named_tup::Tup},
+            markers: quote! {#krate::__named_tup::fields},
+        }
+    }
+}
+
+/// Generates the `__named_tup` module of a namespaced crate, containing its own Tup struct.
+pub fn to_token_stream() -> TokenStream {
+    let namespace = match current() {
+        Some(namespace) => namespace,
+        None => {
+            return syn::Error::new(
+                Span::call_site(),
+                "tup_namespace!() can only be used in a crate that sets `namespace = true` in \
+                `[package.metadata.inwelling.named-tup-derive]` in its Cargo.toml and is a member \
+                of the workspace being built or one of their path dependencies.",
+            )
+            .to_compile_error()
+        }
+    };
+    let identifiers = namespace.identifiers;
+    let krate = Ident::new(namespace.krate, Span::call_site());
+
+    let markers = crate::fields::to_token_stream(identifiers, quote! {named_tup});
+    let info = TupInfo::namespace(identifiers);
+    let full_generics = &info.full_generics;
+    let tup_struct = info.to_token_stream();
+    let own = TupSide::namespace(identifiers, quote! {crate});
+    let mut conversions = vec![
+        to_from_impl(&TupSide::global(), &own),
+        to_from_impl(&own, &TupSide::global()),
+    ];
+    for (dependency, dependency_identifiers) in namespace.dependencies {
+        let dependency = Ident::new(dependency, Span::call_site());
+        let dependency = TupSide::namespace(dependency_identifiers, quote! {::#dependency});
+        conversions.push(to_from_impl(&dependency, &own));
+        conversions.push(to_from_impl(&own, &dependency));
+    }
+
+    quote! {
+        // Lets the tups of the crate refer to it by name, as they also do in its tests and examples.
+        // The crate is already given by name when collecting its doc tests.
+        #[cfg(not(doctest))]
+        extern crate self as #krate;
+
+        #[doc(hidden)]
+        pub mod __named_tup {
+            pub mod fields {
+                #markers
+            }
+
+            #tup_struct
+
+            impl<#full_generics> named_tup::__private::convert::private::Sealed for Tup<#full_generics> {}
+
+            #(#conversions)*
+        }
+    }
+}
+
+/// Converts from the `source` Tup to the `target` Tup, both infallibly and while running the
+/// validators of the target.
+fn to_from_impl(source: &TupSide, target: &TupSide) -> TokenStream {
+    let mut expanded = to_conversion_impl(source, target, Conversion::Strict);
+    expanded.extend(to_conversion_impl(source, target, Conversion::Validating));
+    expanded
+}
+
+/// Converts from the `source` Tup to the `target` Tup. Arguments missing from the source are
+/// treated as unused and arguments missing from the target must be unused.
+fn to_conversion_impl(source: &TupSide, target: &TupSide, conversion: Conversion) -> TokenStream {
+    let TupSide {
+        identifiers: source,
+        tup: source_path,
+        markers: source_markers,
+    } = source;
+    let TupSide {
+        identifiers: target,
+        tup: target_path,
+        markers: target_markers,
+    } = target;
+    let convert = quote! {named_tup::__private::convert};
+    let unused = quote! {named_tup::__private::Unused};
+
+    let source_generics: Vec<Ident> = (0..source.len())
+        .map(|count| format_ident!("T{count}"))
+        .collect();
+    let source_phantom_generics: Vec<Ident> = (0..source.len())
+        .map(|count| format_ident!("P{count}"))
+        .collect();
+    let new_phantom_generics: Vec<Ident> = (0..target.len())
+        .map(|count| format_ident!("N{count}"))
+        .collect();
+
+    let mode = match conversion {
+        Conversion::Validating => quote! {Validating},
+        _ => quote! {Strict},
+    };
+    // Reports the arguments that are missing from the source or unexpected in the target.
+    let check = |old: &TokenStream, new: &TokenStream, marker: TokenStream| {
+        quote! {
+            #old: #convert::ArgumentKind,
+            #new: #convert::ArgumentKind,
+            (<#old as #convert::ArgumentKind>::Kind, <#new as #convert::ArgumentKind>::TargetKind): #convert::CheckArgument<#marker, #convert::#mode>
+        }
+    };
+
+    let mut bounds = vec![];
+    let mut types = vec![];
+    let mut values = vec![];
+    let mut defaults = vec![];
+    let mut old_phantoms = vec![];
+    for (index, (field, new_phantom)) in target.iter().zip(&new_phantom_generics).enumerate() {
+        let source_index = source.iter().position(|s| s == field);
+        let field = Ident::new(field, Span::call_site());
+        let index = syn::Index::from(index);
+        match source_index {
+            Some(i) => {
+                let (generic, phantom) = (&source_generics[i], &source_phantom_generics[i]);
+                old_phantoms.push(phantom.to_token_stream());
+                bounds.push(quote! {#generic: #convert::CanInto<#phantom, #new_phantom, #target_markers::#field>});
+                bounds.push(check(
+                    &phantom.to_token_stream(),
+                    &new_phantom.to_token_stream(),
+                    quote! {#target_markers::#field},
+                ));
+                types
+                    .push(quote! {#convert::Converted<#generic, #phantom, #new_phantom, #target_markers::#field>});
+                defaults.push(quote! {<#generic as #convert::CanInto<#phantom, #new_phantom, #target_markers::#field>>::Default});
+                values.push(quote! {#convert::CanInto::<#phantom, #new_phantom, #target_markers::#field>::into(current.#field, defaults.#index)});
+            }
+            None => {
+                old_phantoms.push(unused.clone());
+                bounds.push(
+                    quote! {(): #convert::CanInto<#unused, #new_phantom, #target_markers::#field>},
+                );
+                bounds.push(check(
+                    &unused,
+                    &new_phantom.to_token_stream(),
+                    quote! {#target_markers::#field},
+                ));
+                types.push(quote! {#convert::Converted<(), #unused, #new_phantom, #target_markers::#field>});
+                defaults.push(quote! {<() as #convert::CanInto<#unused, #new_phantom, #target_markers::#field>>::Default});
+                values.push(quote! {#convert::CanInto::<#unused, #new_phantom, #target_markers::#field>::into((), defaults.#index)});
+            }
+        }
+    }
+    for ((field, generic), phantom) in source
+        .iter()
+        .zip(&source_generics)
+        .zip(&source_phantom_generics)
+    {
+        if !target.contains(field) {
+            let field = Ident::new(field, Span::call_site());
+            bounds.push(
+                quote! {#generic: #convert::CanInto<#phantom, #unused, #source_markers::#field>},
+            );
+            bounds.push(check(
+                &phantom.to_token_stream(),
+                &unused,
+                quote! {#source_markers::#field},
+            ));
+        }
+    }
+
+    let impl_generics = source_generics
+        .iter()
+        .chain(&source_phantom_generics)
+        .chain(&new_phantom_generics);
+    let source_generics: Vec<&Ident> = source_generics
+        .iter()
+        .chain(&source_phantom_generics)
+        .collect();
+
+    let source_type = quote! {#source_path<#(#source_generics),*>};
+    for ((new_phantom, old_phantom), default) in new_phantom_generics
+        .iter()
+        .zip(&old_phantoms)
+        .zip(&defaults)
+    {
+        bounds.push(quote! {#new_phantom: named_tup::__private::tup_struct::ResolveDefault<#old_phantom, #source_type, Default = #default>});
+    }
+    let target_type = quote! {#target_path<#(#types,)* #(#new_phantom_generics),*>};
+    if let Conversion::Validating = conversion {
+        bounds.push(quote! {#target_type: #convert::ValidateTup});
+    }
+
+    let where_clause = match bounds.is_empty() {
+        true => quote! {},
+        false => quote! {
+            where #(#bounds),*
+        },
+    };
+
+    // The defaults are computed from the source before its arguments are moved.
+    let body = quote! {
+        let defaults = (#(<#new_phantom_generics as named_tup::__private::tup_struct::ResolveDefault<#old_phantoms, #source_type>>::resolve(&current),)*);
+        #target_path::new(#(#values),*)
+    };
+
+    match conversion {
+        Conversion::Validating => quote! {
+            impl<#(#impl_generics),*> #convert::TryTupFrom<#source_type> for #target_type
+                #where_clause
+            {
+                #[allow(unused_variables)]
+                fn try_from_tup(current: #source_type) -> Result<Self, #convert::InvalidArgument> {
+                    let converted: Self = { #body };
+                    #convert::ValidateTup::validate(converted)
+                }
+            }
+        },
+        _ => quote! {
+            impl<#(#impl_generics),*> #convert::TupFrom<#source_type> for #target_type
+                #where_clause
+            {
+                #[allow(unused_variables)]
+                fn from_tup(current: #source_type) -> Self {
+                    #body
+                }
+            }
+        },
+    }
+}
@@ -16,7 +16,7 @@ pub fn to_token_stream() -> TokenStream {
         .collect();
 
     quote! {
-        mod private {
+        pub(crate) mod private {
             pub trait Sealed{}
             impl<#(#generics),*> Sealed for crate::tup_struct::Tup<#(#generics),*> {}
         }
@@ -1,50 +1,678 @@
-use proc_macro2::{Ident, Span, TokenStream};
+use std::collections::BTreeMap;
+
+use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::ToTokens;
-use syn::parse::Parser;
+use syn::parse::{ParseStream, Parser};
 use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::visit::Visit;
 use syn::visit_mut::VisitMut;
-use syn::{Token, TypeMacro};
-use uuid::Uuid;
+use syn::{
+    parse_quote, Arm, BinOp, Block, Expr, ExprBinary, ExprCall, ExprClosure, ExprForLoop, ExprIf,
+    ExprLit, ExprPath, ExprWhile, ImplItem, ImplItemFn, Item, ItemImpl, Lit, LitStr, Macro, Pat,
+    PatIdent, Path, Stmt, Token, TypeMacro, Visibility,
+};
 
-use crate::tup_element::{TupDefault, TupType};
+use crate::namespace;
+use crate::tup_element::{Evaluation, TupDefault, TupType, Validator};
+use crate::tup_invocation::check_duplicates;
 
-#[derive(Default)]
 pub struct TupDefaultReplace {
-    pub struct_invocations: TokenStream,
+    errors: TokenStream,
+    /// The hidden module holding the default structs of the annotated item, named after a hash
+    /// of the item so that identical defaults of other items do not clash with them.
+    module: Ident,
+    /// The widest visibility of the items using the structs, given to their module.
+    module_visibility: Visibility,
+    /// The generated default structs along with their implementations, as identical defaults
+    /// share the same struct.
+    structs: BTreeMap<Ident, TokenStream>,
+    /// The visibility of the item currently visited, given to the structs of its defaults.
+    pub visibility: Visibility,
+    /// The visibility every struct has at least, as given by `#[tup_default(pub)]`.
+    pub exported: Visibility,
+    in_trait_impl: bool,
+    in_trait_impl_signature: bool,
+}
+
+/// The widest of two visibilities, so that a struct is visible wherever one of its uses is.
+fn widest(a: Visibility, b: Visibility) -> Visibility {
+    match (&a, &b) {
+        (Visibility::Public(_), _) | (_, Visibility::Inherited) => a,
+        (Visibility::Inherited, _) | (_, Visibility::Public(_)) => b,
+        _ if a.to_token_stream().to_string() == b.to_token_stream().to_string() => a,
+        _ => parse_quote!(pub(crate)),
+    }
+}
+
+fn item_visibility(item: &Item) -> Option<&Visibility> {
+    match item {
+        Item::Const(item) => Some(&item.vis),
+        Item::Enum(item) => Some(&item.vis),
+        Item::Fn(item) => Some(&item.vis),
+        Item::Static(item) => Some(&item.vis),
+        Item::Struct(item) => Some(&item.vis),
+        Item::Trait(item) => Some(&item.vis),
+        Item::Type(item) => Some(&item.vis),
+        Item::Union(item) => Some(&item.vis),
+        _ => None,
+    }
+}
+
+/// A stable FNV-1a hash, so that the same default always produces the same struct name.
+fn hash(contents: &[String]) -> u64 {
+    let mut hash = 0xcbf29ce484222325_u64;
+    for byte in contents
+        .iter()
+        .flat_map(|content| content.bytes().chain([0]))
+    {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Collects the arguments of the same tup that a default expression refers to, skipping the
+/// names bound inside of it by `let`, closures, `match` arms and loops.
+struct DependencyFinder<'a> {
+    elems: &'a [TupType],
+    dependencies: Vec<(usize, Ident)>,
+    /// The names bound in each scope enclosing the expression being visited.
+    scopes: Vec<Vec<Ident>>,
+    error: Option<syn::Error>,
+}
+
+/// The macros whose first string literal is a format string, which can name variables directly.
+const FORMAT_MACROS: &[&str] = &[
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "debug_assert",
+    "debug_assert_eq",
+    "debug_assert_ne",
+    "eprint",
+    "eprintln",
+    "format",
+    "format_args",
+    "panic",
+    "print",
+    "println",
+    "todo",
+    "unimplemented",
+    "unreachable",
+    "write",
+    "writeln",
+];
+
+/// Collects the names bound by a pattern.
+#[derive(Default)]
+struct Bindings(Vec<Ident>);
+
+impl<'ast> Visit<'ast> for Bindings {
+    fn visit_pat_ident(&mut self, i: &'ast PatIdent) {
+        self.0.push(i.ident.clone());
+        syn::visit::visit_pat_ident(self, i);
+    }
+}
+
+fn bindings(pat: &Pat) -> Vec<Ident> {
+    let mut bindings = Bindings::default();
+    bindings.visit_pat(pat);
+    bindings.0
+}
+
+/// Collects the names bound by the `let` expressions of an `if` or `while` condition.
+fn condition_bindings(cond: &Expr) -> Vec<Ident> {
+    match cond {
+        Expr::Let(expr) => bindings(&expr.pat),
+        Expr::Binary(ExprBinary {
+            left,
+            op: BinOp::And(_),
+            right,
+            ..
+        }) => {
+            let mut bindings = condition_bindings(left);
+            bindings.extend(condition_bindings(right));
+            bindings
+        }
+        Expr::Paren(expr) => condition_bindings(&expr.expr),
+        _ => vec![],
+    }
+}
+
+impl DependencyFinder<'_> {
+    fn add(&mut self, ident: &Ident) {
+        if self.scopes.iter().flatten().any(|bound| bound == ident) {
+            return;
+        }
+        let index = self.elems.iter().position(|elem| &elem.name == ident);
+        if let Some(index) = index {
+            if self.dependencies.iter().all(|(i, _)| *i != index) {
+                self.dependencies.push((index, ident.clone()));
+            }
+        }
+    }
+
+    /// Visits `visit` with the names in `bindings` shadowing the arguments.
+    fn scoped(&mut self, bindings: Vec<Ident>, visit: impl FnOnce(&mut Self)) {
+        self.scopes.push(bindings);
+        visit(self);
+        self.scopes.pop();
+    }
+
+    /// Adds the variables named inside of a format string, as in `format!("{width}")`.
+    fn visit_format_string(&mut self, lit: &LitStr) {
+        let value = lit.value();
+        let mut rest = value.as_str();
+        while let Some(start) = rest.find('{') {
+            rest = &rest[start + 1..];
+            if let Some(escaped) = rest.strip_prefix('{') {
+                rest = escaped;
+                continue;
+            }
+            let end = rest.find('}').unwrap_or(rest.len());
+            let (argument, spec) = rest[..end].split_once(':').unwrap_or((&rest[..end], ""));
+            // The width and precision can also name a variable, as in `{:>width$}`.
+            let parameters = spec.split('$').rev().skip(1).filter_map(|part| {
+                part.rsplit(|c: char| c != '_' && !c.is_alphanumeric())
+                    .next()
+            });
+            for name in std::iter::once(argument.trim()).chain(parameters) {
+                if let Ok(ident) = syn::parse_str::<Ident>(name) {
+                    self.add(&Ident::new(&ident.to_string(), lit.span()));
+                }
+            }
+        }
+    }
+
+    /// Reports the first argument used inside of a macro whose arguments could not be read.
+    fn check_unreadable(&mut self, mac: &Macro, tokens: TokenStream) {
+        for token in tokens {
+            match token {
+                TokenTree::Group(group) => self.check_unreadable(mac, group.stream()),
+                TokenTree::Ident(ident) => {
+                    let before = self.dependencies.len();
+                    self.add(&ident);
+                    if self.dependencies.len() > before && self.error.is_none() {
+                        self.dependencies.truncate(before);
+                        let name = mac.path.to_token_stream();
+                        self.error = Some(syn::Error::new(
+                            ident.span(),
+                            format!(
+                                "The arguments of `{name}!` could not be read, so `{ident}` cannot \
+                                 be used inside of it. Bind it to a variable outside of the macro \
+                                 instead."
+                            ),
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for DependencyFinder<'_> {
+    fn visit_expr_path(&mut self, i: &'ast ExprPath) {
+        if let Some(ident) = i.path.get_ident() {
+            self.add(ident);
+        }
+        syn::visit::visit_expr_path(self, i);
+    }
+
+    fn visit_expr_call(&mut self, i: &'ast ExprCall) {
+        // A function called by name, as in `width()`, is not the argument.
+        match &*i.func {
+            Expr::Path(path) if path.path.get_ident().is_some() => {}
+            func => self.visit_expr(func),
+        }
+        for arg in &i.args {
+            self.visit_expr(arg);
+        }
+    }
+
+    fn visit_block(&mut self, i: &'ast Block) {
+        self.scoped(vec![], |finder| {
+            for stmt in &i.stmts {
+                match stmt {
+                    Stmt::Local(local) => {
+                        if let Some(init) = &local.init {
+                            finder.visit_local_init(init);
+                        }
+                        let bindings = bindings(&local.pat);
+                        finder.scopes.last_mut().unwrap().extend(bindings);
+                    }
+                    // Nested items cannot see the arguments.
+                    Stmt::Item(_) => {}
+                    stmt => finder.visit_stmt(stmt),
+                }
+            }
+        });
+    }
+
+    fn visit_expr_closure(&mut self, i: &'ast ExprClosure) {
+        let bindings = i.inputs.iter().flat_map(bindings).collect();
+        self.scoped(bindings, |finder| finder.visit_expr(&i.body));
+    }
+
+    fn visit_arm(&mut self, i: &'ast Arm) {
+        self.scoped(bindings(&i.pat), |finder| {
+            if let Some((_, guard)) = &i.guard {
+                finder.visit_expr(guard);
+            }
+            finder.visit_expr(&i.body);
+        });
+    }
+
+    fn visit_expr_for_loop(&mut self, i: &'ast ExprForLoop) {
+        self.visit_expr(&i.expr);
+        self.scoped(bindings(&i.pat), |finder| finder.visit_block(&i.body));
+    }
+
+    fn visit_expr_if(&mut self, i: &'ast ExprIf) {
+        self.visit_expr(&i.cond);
+        self.scoped(condition_bindings(&i.cond), |finder| {
+            finder.visit_block(&i.then_branch)
+        });
+        if let Some((_, else_branch)) = &i.else_branch {
+            self.visit_expr(else_branch);
+        }
+    }
+
+    fn visit_expr_while(&mut self, i: &'ast ExprWhile) {
+        self.visit_expr(&i.cond);
+        self.scoped(condition_bindings(&i.cond), |finder| {
+            finder.visit_block(&i.body)
+        });
+    }
+
+    fn visit_macro(&mut self, i: &'ast Macro) {
+        let name = i
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string());
+        let is_format = name.is_some_and(|name| FORMAT_MACROS.contains(&name.as_str()));
+        let parser = Punctuated::<Expr, Token![,]>::parse_terminated;
+        // Also read the `[value; count]` form of `vec!`.
+        let repeat = |input: ParseStream| -> syn::Result<Vec<Expr>> {
+            let value = input.parse()?;
+            input.parse::<Token![;]>()?;
+            Ok(vec![value, input.parse()?])
+        };
+        let args = match parser.parse2(i.tokens.clone()) {
+            Ok(args) => args.into_iter().collect(),
+            Err(_) => match repeat.parse2(i.tokens.clone()) {
+                Ok(args) => args,
+                Err(_) => return self.check_unreadable(i, i.tokens.clone()),
+            },
+        };
+        let format_string = args.iter().find_map(|arg| match arg {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) if is_format => Some(lit),
+            _ => None,
+        });
+        if let Some(lit) = format_string {
+            self.visit_format_string(lit);
+        }
+        for arg in &args {
+            self.visit_expr(arg);
+        }
+    }
+}
+
+fn find_dependencies(expr: &Expr, elems: &[TupType]) -> syn::Result<Vec<(usize, Ident)>> {
+    let mut finder = DependencyFinder {
+        elems,
+        dependencies: vec![],
+        scopes: vec![],
+        error: None,
+    };
+    finder.visit_expr(expr);
+    match finder.error {
+        Some(err) => Err(err),
+        None => Ok(finder.dependencies),
+    }
+}
+
+/// Checks that the defaults do not depend on each other in a cycle, pointing at the dependency
+/// that closes it.
+fn check_cycles(dependencies: &[Vec<(usize, Ident)>]) -> syn::Result<()> {
+    fn visit(
+        index: usize,
+        dependencies: &[Vec<(usize, Ident)>],
+        visiting: &mut Vec<usize>,
+        done: &mut Vec<usize>,
+    ) -> syn::Result<()> {
+        if done.contains(&index) {
+            return Ok(());
+        }
+        visiting.push(index);
+        for (dependency, ident) in &dependencies[index] {
+            if visiting.contains(dependency) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("This default depends on `{ident}` in a cycle, so it can never be computed."),
+                ));
+            }
+            visit(*dependency, dependencies, visiting, done)?;
+        }
+        visiting.pop();
+        done.push(index);
+        Ok(())
+    }
+
+    let mut done = vec![];
+    for index in 0..dependencies.len() {
+        visit(index, dependencies, &mut vec![], &mut done)?;
+    }
+    Ok(())
 }
 
 impl TupDefaultReplace {
-    fn produce_expr_struct(&mut self, elem: &mut TupType) {
-        if let TupDefault::Unfinished(expr) = &elem.default {
-            let struct_name = format_ident!("__{}_{}", elem.name, Uuid::new_v4().as_u128());
-            let expr_type = &elem.value;
-            let struct_tokens = quote! {
-                #[allow(non_camel_case_types)]
-                #[derive(Default, Copy, Clone)]
-                struct #struct_name;
-                impl named_tup::__private::TupDefault for #struct_name {
-                    type Output = #expr_type;
-
-                    fn default() -> Self::Output {
-                        #expr
+    pub fn new(item: &impl ToTokens) -> Self {
+        TupDefaultReplace {
+            errors: TokenStream::new(),
+            module: format_ident!(
+                "__tup_default_{:016x}",
+                hash(&[item.to_token_stream().to_string()])
+            ),
+            module_visibility: Visibility::Inherited,
+            structs: BTreeMap::new(),
+            visibility: Visibility::Inherited,
+            exported: Visibility::Inherited,
+            in_trait_impl: false,
+            in_trait_impl_signature: false,
+        }
+    }
+
+    /// The generated default structs, along with any errors found while generating them.
+    pub fn into_token_stream(self) -> TokenStream {
+        let errors = self.errors;
+        if self.structs.is_empty() {
+            return errors;
+        }
+        // The implementations stay next to the item, so that the default expressions are
+        // evaluated in its scope.
+        let (struct_names, impls): (Vec<Ident>, Vec<TokenStream>) =
+            self.structs.into_iter().unzip();
+        let module = self.module;
+        let visibility = self.module_visibility;
+        quote! {
+            #errors
+            #[doc(hidden)]
+            #visibility mod #module {
+                #(
+                    #[allow(non_camel_case_types)]
+                    #[derive(Default, Copy, Clone)]
+                    pub struct #struct_names;
+                )*
+            }
+            #(#impls)*
+        }
+    }
+
+    /// The path to a generated struct from the annotated item.
+    fn struct_path(&self, struct_name: &Ident) -> Path {
+        let module = &self.module;
+        parse_quote!(#module::#struct_name)
+    }
+
+    /// Reports an error, removing the unfinished defaults and validators so that the Tup! macro
+    /// does not report them as well.
+    fn report(&mut self, err: syn::Error, elems: &mut [TupType]) {
+        self.errors.extend(err.to_compile_error());
+        for elem in elems {
+            if let TupDefault::Unfinished(_) = elem.default {
+                elem.default = TupDefault::None;
+            }
+            if let Validator::Unfinished(_) = elem.validator {
+                elem.validator = Validator::None;
+            }
+        }
+    }
+
+    /// Adds a generated struct, widening the visibility of the module to the one of its use.
+    fn insert_struct(&mut self, struct_name: Ident, struct_tokens: TokenStream) {
+        let visibility = widest(self.visibility.clone(), self.exported.clone());
+        self.module_visibility = widest(self.module_visibility.clone(), visibility);
+        self.structs.entry(struct_name).or_insert(struct_tokens);
+    }
+
+    /// Replaces the validators with structs that check the value of their argument.
+    fn produce_validator_structs(&mut self, elems: &mut [TupType]) {
+        for elem in elems {
+            if let Validator::Unfinished(validator) = &elem.validator {
+                let name = &elem.name;
+                let value = &elem.value;
+                let contents = [quote! {#name: #value where #validator}.to_string()];
+                let struct_name = format_ident!("__{}_valid_{:016x}", name, hash(&contents));
+                let struct_path = self.struct_path(&struct_name);
+                let struct_tokens = quote! {
+                    impl named_tup::__private::TupValidator for #struct_path {
+                        type Value = #value;
+
+                        fn validate(#name: Self::Value) -> Result<Self::Value, Self::Value> {
+                            let valid: bool = #validator;
+                            match valid {
+                                true => Ok(#name),
+                                false => Err(#name),
+                            }
+                        }
+                    }
+                };
+                self.insert_struct(struct_name, struct_tokens);
+                elem.validator = Validator::Finished(struct_path);
+            }
+        }
+    }
+
+    fn produce_expr_structs(&mut self, elems: &mut [TupType]) {
+        let dependencies: syn::Result<Vec<Vec<(usize, Ident)>>> = elems
+            .iter()
+            .map(|elem| match &elem.default {
+                TupDefault::Unfinished(expr) => find_dependencies(expr, elems),
+                _ => Ok(vec![]),
+            })
+            .collect();
+        let dependencies = match dependencies {
+            Ok(dependencies) => dependencies,
+            Err(err) => {
+                self.report(err, elems);
+                return;
+            }
+        };
+        // A dependent default also uses the defaults of its dependencies, so it is named after the
+        // whole invocation.
+        let invocation: Vec<String> = elems
+            .iter()
+            .map(|elem| elem.default_tokens().to_string())
+            .collect();
+        let struct_names: Vec<Option<Path>> = elems
+            .iter()
+            .zip(&dependencies)
+            .map(|(elem, dependencies)| match &elem.default {
+                TupDefault::None => None,
+                TupDefault::Unfinished(_) => {
+                    let mut contents = vec![elem.default_tokens().to_string()];
+                    if !dependencies.is_empty() {
+                        contents.extend(invocation.iter().cloned());
                     }
+                    let struct_name = format_ident!("__{}_{:016x}", elem.name, hash(&contents));
+                    Some(self.struct_path(&struct_name))
                 }
+                TupDefault::Finished(path) => Some(path.clone()),
+            })
+            .collect();
+
+        if let Err(err) = check_cycles(&dependencies) {
+            self.report(err, elems);
+            return;
+        }
+        // Lazy and const defaults are evaluated without a tup, so they cannot depend on one.
+        for (elem, dependencies) in elems.iter().zip(&dependencies) {
+            let kind = match elem.evaluation {
+                Evaluation::Each => continue,
+                Evaluation::Lazy => "lazy",
+                Evaluation::Const => "const",
             };
-            self.struct_invocations.extend(struct_tokens);
-            elem.default = TupDefault::Finished(struct_name);
+            if let Some((_, ident)) = dependencies.first() {
+                let err = syn::Error::new(
+                    ident.span(),
+                    format!("A {kind} default cannot depend on other arguments."),
+                );
+                self.report(err, elems);
+                return;
+            }
+        }
+        // Lazy defaults are cached in a `OnceLock`, which only exists with std.
+        if !cfg!(feature = "std") {
+            let lazy = elems
+                .iter()
+                .find_map(|elem| match (elem.evaluation, &elem.default) {
+                    (Evaluation::Lazy, TupDefault::Unfinished(expr)) => Some(expr.span()),
+                    _ => None,
+                });
+            if let Some(span) = lazy {
+                let err = syn::Error::new(
+                    span,
+                    "Lazy defaults require the `std` feature of named-tup, use `= expr` to \
+                     evaluate the default every time instead.",
+                );
+                self.report(err, elems);
+                return;
+            }
+        }
+
+        for (index, dependencies) in dependencies.iter().enumerate() {
+            let dependency_names: Vec<&Ident> =
+                dependencies.iter().map(|(_, ident)| ident).collect();
+            let dependency_types: Vec<syn::Type> = dependencies
+                .iter()
+                .map(|(i, _)| elems[*i].value.clone())
+                .collect();
+            // An argument without a default has to be set in the tup being converted.
+            let dependency_phantoms: Vec<TokenStream> = dependencies
+                .iter()
+                .map(|(i, _)| match &struct_names[*i] {
+                    Some(struct_path) => struct_path.to_token_stream(),
+                    None => quote! {named_tup::__private::Used},
+                })
+                .collect();
+            let struct_path = struct_names[index].clone();
+            let elem = &mut elems[index];
+            if let (TupDefault::Unfinished(expr), Some(struct_path)) = (&elem.default, struct_path)
+            {
+                let expr_type = &elem.value;
+                let markers = namespace::markers_path();
+                let default = match elem.evaluation {
+                    Evaluation::Each => quote! {
+                        #(let #dependency_names = named_tup::__private::ArgumentOr::<#markers::#dependency_names, #dependency_phantoms, #dependency_types, __S>::argument_or(source, source);)*
+                        #expr
+                    },
+                    Evaluation::Lazy => quote! {
+                        static VALUE: named_tup::__private::OnceLock<#expr_type> = named_tup::__private::OnceLock::new();
+                        core::clone::Clone::clone(VALUE.get_or_init(|| #expr))
+                    },
+                    Evaluation::Const => quote! {
+                        const VALUE: #expr_type = #expr;
+                        VALUE
+                    },
+                };
+                let struct_tokens = quote! {
+                    impl named_tup::__private::TupDefault for #struct_path {
+                        type Output = #expr_type;
+                    }
+
+                    impl<__S> named_tup::__private::TupDefaultFrom<__S> for #struct_path
+                        where #(__S: named_tup::__private::ArgumentOr<#markers::#dependency_names, #dependency_phantoms, #dependency_types, __S>),*
+                    {
+                        #[allow(unused_variables)]
+                        fn default_from(source: &__S) -> Self::Output {
+                            #default
+                        }
+                    }
+                };
+                let struct_name = struct_path.segments.last().unwrap().ident.clone();
+                self.insert_struct(struct_name, struct_tokens);
+                elem.default = TupDefault::Finished(struct_path);
+            }
         }
     }
 }
 
 impl VisitMut for TupDefaultReplace {
+    fn visit_item_mut(&mut self, i: &mut Item) {
+        // The structs are declared next to the annotated item, where the paths to them from
+        // within a module would not resolve.
+        if let Item::Mod(item) = i {
+            let err = syn::Error::new(
+                item.ident.span(),
+                "#[tup_default] cannot be used on a module, use it on the items of the module \
+                 instead.",
+            );
+            self.errors.extend(err.to_compile_error());
+            return;
+        }
+        let visibility = item_visibility(i).cloned().unwrap_or(Visibility::Inherited);
+        let old = std::mem::replace(&mut self.visibility, visibility);
+        syn::visit_mut::visit_item_mut(self, i);
+        self.visibility = old;
+    }
+
+    fn visit_item_impl_mut(&mut self, i: &mut ItemImpl) {
+        let old = std::mem::replace(&mut self.in_trait_impl, i.trait_.is_some());
+        syn::visit_mut::visit_item_impl_mut(self, i);
+        self.in_trait_impl = old;
+    }
+
+    fn visit_impl_item_mut(&mut self, i: &mut ImplItem) {
+        // The items of a trait implementation are as visible as the trait itself.
+        let visibility = match (self.in_trait_impl, &*i) {
+            (true, _) => parse_quote!(pub),
+            (false, ImplItem::Const(item)) => item.vis.clone(),
+            (false, ImplItem::Fn(item)) => item.vis.clone(),
+            (false, ImplItem::Type(item)) => item.vis.clone(),
+            _ => Visibility::Inherited,
+        };
+        let old = std::mem::replace(&mut self.visibility, visibility);
+        syn::visit_mut::visit_impl_item_mut(self, i);
+        self.visibility = old;
+    }
+
+    fn visit_impl_item_fn_mut(&mut self, i: &mut ImplItemFn) {
+        self.in_trait_impl_signature = self.in_trait_impl;
+        self.visit_signature_mut(&mut i.sig);
+        self.in_trait_impl_signature = false;
+        self.visit_block_mut(&mut i.block);
+    }
+
     fn visit_type_macro_mut(&mut self, i: &mut TypeMacro) {
-        if i.mac.path.is_ident(&Ident::new("Tup", Span::call_site())) {
+        if i.mac.path.is_ident("Tup") || i.mac.path.is_ident("HTup") {
             let parser = Punctuated::<TupType, Token![,]>::parse_terminated;
-            if let Ok(mut v) = parser.parse2(i.mac.tokens.clone()) {
-                let new_expr = v.iter_mut().map(|elem| {
-                    self.produce_expr_struct(elem);
-                    elem.to_token_stream()
-                });
+            if let Ok(v) = parser.parse2(i.mac.tokens.clone()) {
+                // Leave the invocation untouched so that the Tup! macro reports the duplicate.
+                if check_duplicates(v.iter().map(|elem| &elem.name)).is_err() {
+                    return;
+                }
+                let mut v: Vec<TupType> = v.into_iter().collect();
+                // Every item gets its own default structs, so the ones of a trait implementation
+                // would never match the ones of the trait.
+                let defaulted = v
+                    .iter()
+                    .any(|elem| matches!(elem.default, TupDefault::Unfinished(_)));
+                if self.in_trait_impl_signature && defaulted {
+                    let err = syn::Error::new(
+                        i.span(),
+                        "Defaults in the implementation of a trait method never match the ones of \
+                         the trait, declare the type once with tup_type! and use it in both instead.",
+                    );
+                    self.report(err, &mut v);
+                }
+                self.produce_expr_structs(&mut v);
+                self.produce_validator_structs(&mut v);
+                let new_expr = v.iter().map(|elem| elem.to_token_stream());
                 i.mac.tokens = quote!(#(#new_expr),*);
             };
         }
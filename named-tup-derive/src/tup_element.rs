@@ -1,14 +1,32 @@
 use std::cmp::Ordering;
 
-use proc_macro2::{Ident, Punct, Spacing, TokenStream};
+use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream};
 use quote::{ToTokens, TokenStreamExt};
 use syn::parse::{Parse, ParseStream};
-use syn::{Expr, Token, Type};
+use syn::{bracketed, Expr, Path, Token, Type};
 
 pub enum TupDefault {
     None,
     Unfinished(Box<Expr>),
-    Finished(Ident),
+    Finished(Path),
+}
+
+/// The validator of an argument, given with `where`.
+pub enum Validator {
+    None,
+    Unfinished(Box<Expr>),
+    Finished(Path),
+}
+
+/// When the default expression of an argument is evaluated.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Evaluation {
+    /// Every time the default is needed.
+    Each,
+    /// Once per process with `= #[lazy] expr`, the value then being cloned.
+    Lazy,
+    /// At compile time with `= const expr`.
+    Const,
 }
 
 pub struct TupElement {
@@ -40,6 +58,8 @@ pub struct TupType {
     pub name: Ident,
     pub value: Type,
     pub default: TupDefault,
+    pub evaluation: Evaluation,
+    pub validator: Validator,
 }
 
 impl Ord for TupType {
@@ -81,43 +101,119 @@ impl Parse for TupType {
         let name = input.parse()?;
         input.parse::<Token![:]>()?;
         let value = input.parse()?;
+        let mut evaluation = Evaluation::Each;
         let default = match input.peek(Token![=]) {
             true => {
                 input.parse::<Token![=]>()?;
                 match input.peek(Token![=]) {
                     true => {
                         input.parse::<Token![=]>()?;
-                        TupDefault::Finished(input.parse::<Ident>()?)
+                        TupDefault::Finished(input.parse::<Path>()?)
+                    }
+                    false => {
+                        evaluation = parse_evaluation(input)?;
+                        TupDefault::Unfinished(Box::new(input.parse::<Expr>()?))
                     }
-                    false => TupDefault::Unfinished(Box::new(input.parse::<Expr>()?)),
                 }
             }
             false => TupDefault::None,
         };
+        let validator = match input.peek(Token![where]) {
+            true => {
+                input.parse::<Token![where]>()?;
+                match input.peek(Token![=]) {
+                    true => {
+                        input.parse::<Token![=]>()?;
+                        Validator::Finished(input.parse::<Path>()?)
+                    }
+                    false => Validator::Unfinished(Box::new(input.parse::<Expr>()?)),
+                }
+            }
+            false => Validator::None,
+        };
         Ok(TupType {
             name,
             value,
             default,
+            evaluation,
+            validator,
         })
     }
 }
 
-impl ToTokens for TupType {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        self.name.to_tokens(tokens);
+/// Parses the `#[lazy]` attribute or the `const` keyword that may come before a default
+/// expression. An attribute is used for lazy defaults as `lazy` is not a keyword, so it could
+/// also start an expression such as `lazy(x)` or `lazy::value()`.
+fn parse_evaluation(input: ParseStream) -> syn::Result<Evaluation> {
+    if input.peek(Token![const]) {
+        input.parse::<Token![const]>()?;
+        return Ok(Evaluation::Const);
+    }
+    if input.peek(Token![#]) && input.fork().call(parse_lazy).is_ok() {
+        input.call(parse_lazy)?;
+        return Ok(Evaluation::Lazy);
+    }
+    Ok(Evaluation::Each)
+}
+
+/// Parses `#[lazy]`, other attributes being left to the default expression.
+fn parse_lazy(input: ParseStream) -> syn::Result<()> {
+    input.parse::<Token![#]>()?;
+    let content;
+    bracketed!(content in input);
+    let ident = content.parse::<Ident>()?;
+    if ident != "lazy" || !content.is_empty() {
+        return Err(syn::Error::new(ident.span(), "Expected `#[lazy]`."));
+    }
+    Ok(())
+}
+
+impl TupType {
+    /// The tokens of the argument without its validator.
+    pub fn default_tokens(&self) -> TokenStream {
+        let mut tokens = TokenStream::new();
+        self.name.to_tokens(&mut tokens);
         tokens.append(Punct::new(':', Spacing::Alone));
-        self.value.to_tokens(tokens);
+        self.value.to_tokens(&mut tokens);
         match &self.default {
             TupDefault::Unfinished(expr) => {
                 tokens.append(Punct::new('=', Spacing::Alone));
-                expr.to_tokens(tokens)
+                match self.evaluation {
+                    Evaluation::Each => {}
+                    Evaluation::Lazy => {
+                        tokens.append(Punct::new('#', Spacing::Alone));
+                        let lazy = Ident::new("lazy", Span::call_site()).into_token_stream();
+                        tokens.append(Group::new(Delimiter::Bracket, lazy));
+                    }
+                    Evaluation::Const => <Token![const]>::default().to_tokens(&mut tokens),
+                }
+                expr.to_tokens(&mut tokens);
             }
-            TupDefault::Finished(ident) => {
+            TupDefault::Finished(path) => {
                 tokens.append(Punct::new('=', Spacing::Joint));
                 tokens.append(Punct::new('=', Spacing::Alone));
-                ident.to_tokens(tokens)
+                path.to_tokens(&mut tokens)
             }
             TupDefault::None => {}
         }
+        tokens
+    }
+}
+
+impl ToTokens for TupType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(self.default_tokens());
+        match &self.validator {
+            Validator::Unfinished(expr) => {
+                <Token![where]>::default().to_tokens(tokens);
+                expr.to_tokens(tokens);
+            }
+            Validator::Finished(path) => {
+                <Token![where]>::default().to_tokens(tokens);
+                tokens.append(Punct::new('=', Spacing::Alone));
+                path.to_tokens(tokens);
+            }
+            Validator::None => {}
+        }
     }
 }
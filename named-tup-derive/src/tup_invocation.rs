@@ -1,13 +1,13 @@
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::{quote, quote_spanned, ToTokens};
+use quote::{quote, ToTokens};
 use syn::spanned::Spanned;
 use syn::{
     parse::{Parse, ParseStream, Result},
     parse_quote, Token, Type,
 };
 
-use crate::tup_element::{TupDefault, TupElement, TupType};
-use crate::IDENTIFIERS;
+use crate::namespace;
+use crate::tup_element::{TupDefault, TupElement, TupType, Validator};
 
 pub struct TupElementInvocation(Vec<TupElement>);
 
@@ -19,6 +19,7 @@ impl Parse for TupElementInvocation {
             .parse_terminated(TupElement::parse, Token![,])?
             .into_iter()
             .collect();
+        check_duplicates(values.iter().map(|v| &v.name))?;
         values.sort();
         Ok(TupElementInvocation(values))
     }
@@ -30,24 +31,96 @@ impl Parse for TupTypeInvocation {
             .parse_terminated(TupType::parse, Token![,])?
             .into_iter()
             .collect();
+        check_duplicates(values.iter().map(|v| &v.name))?;
         values.sort();
         Ok(TupTypeInvocation(values))
     }
 }
 
+/// Checks that no identifier is repeated, pointing at the second occurrence otherwise.
+pub fn check_duplicates<'a>(names: impl Iterator<Item = &'a Ident>) -> Result<()> {
+    let mut seen: Vec<&Ident> = vec![];
+    for name in names {
+        if seen.contains(&name) {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("The argument `{name}` is specified more than once."),
+            ));
+        }
+        seen.push(name);
+    }
+    Ok(())
+}
+
+/// Produces an error for an identifier that was not found when the crate was built.
+fn unknown_identifier_error(name: &Ident) -> syn::Error {
+    let name_str = name.to_string();
+    let suggestion = namespace::identifiers()
+        .iter()
+        .map(|identifier| (edit_distance(&name_str, identifier), identifier))
+        .filter(|(distance, _)| *distance <= name_str.len() / 3 + 1)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, identifier)| format!(" Did you mean `{identifier}`?"))
+        .unwrap_or_default();
+    syn::Error::new(
+        name.span(),
+        format!(
+            "`{name_str}` is not a known tup argument.{suggestion}\n\
+            If it is a new argument, either make sure it is used in a tup!/Tup! invocation \
+            in your source files so that it can be found, or register it in one of: \
+            the `arguments` list in `[package.metadata.inwelling.named-tup-derive]` in your \
+            Cargo.toml, the `arguments` list of a `named-tup.toml` next to your workspace, \
+            or the `NAMED_TUP_ARGUMENTS` environment variable."
+        ),
+    )
+}
+
+/// Checks that every identifier exists, combining the errors of all the unknown ones.
+fn check_identifiers<'a>(names: impl Iterator<Item = &'a Ident>) -> Result<()> {
+    let identifiers = namespace::identifiers();
+    names
+        .filter(|name| !identifiers.contains(&name.to_string().as_str()))
+        .map(unknown_identifier_error)
+        .reduce(|mut acc, err| {
+            acc.combine(err);
+            acc
+        })
+        .map_or(Ok(()), Err)
+}
+
+/// The Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous + usize::from(a_char != *b_char);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(previous + 1).min(row[j] + 1);
+        }
+    }
+    row[b.len()]
+}
+
 impl TupElementInvocation {
     pub fn into_token_stream(self) -> TokenStream {
+        if let Err(err) = check_identifiers(self.0.iter().map(|v| &v.name)) {
+            let err = err.to_compile_error();
+            return quote! {{ #err }};
+        }
         let mut expressions = vec![];
         let mut identifiers = vec![];
         let mut generics: Vec<Type> = vec![];
-        let empty = (0..IDENTIFIERS.len()).map(|_| Ident::new("_", Span::call_site()));
+        let empty = (0..namespace::identifiers().len()).map(|_| Ident::new("_", Span::call_site()));
         let mut values = self
             .0
             .into_iter()
             .map(|v| (v.name.to_string(), v))
             .peekable();
 
-        for identifier in IDENTIFIERS {
+        for identifier in namespace::identifiers() {
             match values.peek() {
                 Some((val, _)) if val == identifier => {
                     let elem = values.next().unwrap();
@@ -67,20 +140,66 @@ impl TupElementInvocation {
             }
         }
 
-        assert!(
-            values.next().is_none(),
-            "tup! invocation contained identifiers that did not match to any known identifiers"
-        );
-
+        let tup_path = namespace::tup_path();
         let expanded = quote! {
-            named_tup::__private::Tup::<#(#empty),* , #(#generics),*>::new( #(#expressions),* )
+            #tup_path::<#(#empty),* , #(#generics),*>::new( #(#expressions),* )
         };
         expanded
     }
+
+    /// Builds the tup as a list of `HCons` cells sorted by argument name.
+    #[cfg(feature = "hlist")]
+    pub fn into_hlist_token_stream(self) -> TokenStream {
+        if let Err(err) = check_identifiers(self.0.iter().map(|v| &v.name)) {
+            let err = err.to_compile_error();
+            return quote! {{ #err }};
+        }
+        let markers = namespace::markers_path();
+        self.0.into_iter().rev().fold(
+            quote! {named_tup::hlist::HNil},
+            |rest, elem| {
+                let name = elem.name;
+                let value = elem
+                    .value
+                    .unwrap_or_else(|| syn::Expr::Verbatim(name.to_token_stream()));
+                quote! {
+                    named_tup::hlist::HCons::<#markers::#name, _, named_tup::__private::Used, _>::new(#value, #rest)
+                }
+            },
+        )
+    }
+}
+
+/// The phantom type of a contained argument, telling whether it is set or defaulted and how it is
+/// validated.
+fn phantom(default: TupDefault, validator: Validator) -> Result<Type> {
+    let unfinished = |span| {
+        syn::Error::new(
+            span,
+            "Use the #[tup_default] attribute to automatically derive a struct for each default \
+             and validator expression.",
+        )
+    };
+    let phantom: Type = match default {
+        TupDefault::None => parse_quote!(named_tup::__private::Used),
+        TupDefault::Unfinished(expr) => return Err(unfinished(expr.span())),
+        TupDefault::Finished(path) => parse_quote!(#path),
+    };
+    match validator {
+        Validator::None => Ok(phantom),
+        Validator::Unfinished(expr) => Err(unfinished(expr.span())),
+        Validator::Finished(path) => {
+            Ok(parse_quote!(named_tup::__private::Validated<#phantom, #path>))
+        }
+    }
 }
 
 impl TupTypeInvocation {
     pub fn into_token_stream(self) -> TokenStream {
+        // Only a single error can be placed in a type position.
+        if let Err(err) = check_identifiers(self.0.iter().map(|v| &v.name)) {
+            return err.into_iter().next().unwrap().to_compile_error();
+        }
         let mut types = vec![];
         let mut phantom_generics = vec![];
         let mut values = self
@@ -89,20 +208,14 @@ impl TupTypeInvocation {
             .map(|v| (v.name.to_string(), v))
             .peekable();
 
-        for identifier in IDENTIFIERS {
+        for identifier in namespace::identifiers() {
             match values.peek() {
                 Some((val, _)) if val == identifier => {
                     let elem = values.next().unwrap();
                     types.push(elem.1.value);
-                    match elem.1.default {
-                        TupDefault::None => {
-                            phantom_generics.push(parse_quote!(named_tup::__private::Used))
-                        }
-                        TupDefault::Unfinished(expr) => {
-                            return quote_spanned! {expr.span() => compile_error("Use the #[tup_default] attribute to automatically derive a TupDefault struct for each expression.");};
-                        }
-                        TupDefault::Finished(ident) => phantom_generics
-                            .push(syn::parse2::<Type>(ident.to_token_stream()).unwrap()),
+                    match phantom(elem.1.default, elem.1.validator) {
+                        Ok(phantom) => phantom_generics.push(phantom),
+                        Err(err) => return err.to_compile_error(),
                     }
                 }
                 _ => {
@@ -112,15 +225,34 @@ impl TupTypeInvocation {
             }
         }
 
-        assert!(
-            values.next().is_none(),
-            "Tup! invocation contained identifiers that did not match to any known identifiers"
-        );
-
+        let tup_path = namespace::tup_path();
         let expanded = quote! {
-            named_tup::__private::Tup::<#(#types),* , #(#phantom_generics),*>
+            #tup_path::<#(#types),* , #(#phantom_generics),*>
         };
 
         expanded
     }
+
+    /// Builds the type of the tup as a list of `HCons` cells sorted by argument name.
+    #[cfg(feature = "hlist")]
+    pub fn into_hlist_token_stream(self) -> TokenStream {
+        // Only a single error can be placed in a type position.
+        if let Err(err) = check_identifiers(self.0.iter().map(|v| &v.name)) {
+            return err.into_iter().next().unwrap().to_compile_error();
+        }
+        let markers = namespace::markers_path();
+        let mut expanded = quote! {named_tup::hlist::HNil};
+        for elem in self.0.into_iter().rev() {
+            let phantom = match phantom(elem.default, elem.validator) {
+                Ok(phantom) => phantom,
+                Err(err) => return err.to_compile_error(),
+            };
+            let name = elem.name;
+            let value = elem.value;
+            expanded = quote! {
+                named_tup::hlist::HCons<#markers::#name, #value, #phantom, #expanded>
+            };
+        }
+        expanded
+    }
 }
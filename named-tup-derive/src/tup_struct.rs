@@ -3,25 +3,71 @@ use syn::spanned::Spanned;
 
 use crate::IDENTIFIERS;
 
+/// The conversions between tups, see the `Strict`, `Lossy` and `Validating` modes of named-tup.
+#[derive(Copy, Clone)]
+pub(crate) enum Conversion {
+    Strict,
+    Lossy,
+    Validating,
+}
+
 pub(crate) struct TupInfo {
     pub fields: Vec<Ident>,
     pub generics: Vec<Ident>,
     pub phantom_generics: Vec<Ident>,
     pub full_generics: TokenStream,
+    /// The path to the helper modules of named-tup.
+    pub krate: TokenStream,
+    /// The path to the module containing the field marker types.
+    pub markers: TokenStream,
+    /// Whether the Tup is generated in a downstream crate by `tup_namespace!`.
+    pub is_namespace: bool,
+}
+
+/// The generics of the right hand side tup of an operation combining two tups, see
+/// [`TupInfo::rhs_generics`].
+struct RhsGenerics {
+    generics: Vec<Ident>,
+    /// The phantom of each argument of the left hand side, without its validator.
+    phantoms: Vec<TokenStream>,
+    /// The phantom of each argument of the right hand side, without its validator.
+    rhs_phantoms: Vec<TokenStream>,
+    full_generics: TokenStream,
+    /// The start of the where clause of the operation, to which the bound on each argument is
+    /// appended.
+    where_clause: TokenStream,
 }
 
 impl TupInfo {
     pub(crate) fn new() -> TupInfo {
-        let fields: Vec<Ident> = IDENTIFIERS
+        TupInfo::with_identifiers(IDENTIFIERS, quote! {crate}, quote! {crate::fields}, false)
+    }
+
+    pub(crate) fn namespace(identifiers: &[&str]) -> TupInfo {
+        TupInfo::with_identifiers(
+            identifiers,
+            quote! {named_tup::__private},
+            quote! {crate::__named_tup::fields},
+            true,
+        )
+    }
+
+    fn with_identifiers(
+        identifiers: &[&str],
+        krate: TokenStream,
+        markers: TokenStream,
+        is_namespace: bool,
+    ) -> TupInfo {
+        let fields: Vec<Ident> = identifiers
             .iter()
             .map(|i| Ident::new(i, Span::call_site()))
             .collect();
-        let generics: Vec<Ident> = IDENTIFIERS
+        let generics: Vec<Ident> = identifiers
             .iter()
             .enumerate()
             .map(|(count, field)| Ident::new(&format!("T{count}"), field.span()))
             .collect();
-        let phantom_generics: Vec<Ident> = IDENTIFIERS
+        let phantom_generics: Vec<Ident> = identifiers
             .iter()
             .enumerate()
             .map(|(count, field)| Ident::new(&format!("P{count}"), field.span()))
@@ -37,6 +83,9 @@ impl TupInfo {
             generics,
             phantom_generics,
             full_generics,
+            krate,
+            markers,
+            is_namespace,
         }
     }
 
@@ -46,6 +95,7 @@ impl TupInfo {
             fields,
             phantom_generics,
             full_generics,
+            ..
         } = self;
 
         let expanded = quote! {
@@ -84,11 +134,194 @@ impl TupInfo {
         expanded
     }
 
+    fn to_builder_impl(&self) -> TokenStream {
+        let Self {
+            generics,
+            fields,
+            phantom_generics,
+            full_generics,
+            ..
+        } = self;
+        let krate = &self.krate;
+
+        let methods = fields.iter().enumerate().map(|(index, field)| {
+            let with = format_ident!("with_{field}");
+            let without = format_ident!("without_{field}");
+            let others: Vec<&Ident> = fields.iter().filter(|other| *other != field).collect();
+            // The tup type with the generics of the field replaced.
+            let output = |value: TokenStream, phantom: TokenStream| {
+                let values = generics.iter().enumerate().map(|(i, g)| match i == index {
+                    true => value.clone(),
+                    false => quote! {#g},
+                });
+                let phantoms = phantom_generics
+                    .iter()
+                    .enumerate()
+                    .map(|(i, g)| match i == index {
+                        true => phantom.clone(),
+                        false => quote! {#g},
+                    });
+                quote! {Tup<#(#values,)* #(#phantoms),*>}
+            };
+            let with_output = output(quote! {__V}, quote! {#krate::tup_struct::Used});
+            let without_output = output(quote! {()}, quote! {#krate::tup_struct::Unused});
+            let with_doc = format!("Sets the `{field}` argument, replacing its value or default.");
+            let without_doc = format!("Removes the `{field}` argument from the tup.");
+
+            quote! {
+                #[doc = #with_doc]
+                pub fn #with<__V>(self, #field: __V) -> #with_output {
+                    Tup {
+                        #(#others: self.#others,)*
+                        #field,
+                        _phantom: core::marker::PhantomData
+                    }
+                }
+
+                #[doc = #without_doc]
+                pub fn #without(self) -> #without_output {
+                    Tup {
+                        #(#others: self.#others,)*
+                        #field: (),
+                        _phantom: core::marker::PhantomData
+                    }
+                }
+            }
+        });
+
+        let expanded = quote! {
+            #[allow(clippy::type_complexity)]
+            impl<#full_generics> Tup<#full_generics> {
+                #(#methods)*
+            }
+        };
+
+        expanded
+    }
+
+    fn to_reflection_impl(&self) -> TokenStream {
+        let Self {
+            generics,
+            fields,
+            phantom_generics,
+            full_generics,
+            ..
+        } = self;
+        let krate = &self.krate;
+
+        let len = fields.len();
+
+        let where_clause = match fields.is_empty() {
+            true => quote! {},
+            false => quote! {
+                where #(#phantom_generics: #krate::tup_struct::FieldPresence),*
+            },
+        };
+
+        let visitor_where_clause = match fields.is_empty() {
+            true => quote! {},
+            false => quote! {
+                where #(#phantom_generics: #krate::tup_struct::VisitField<#generics>),*
+            },
+        };
+
+        let expanded = quote! {
+            impl<#full_generics> Tup<#full_generics>
+                #where_clause
+            {
+                /// The number of arguments contained in the tup.
+                pub const LEN: usize = 0 #(+ <#phantom_generics as #krate::tup_struct::FieldPresence>::PRESENT as usize)*;
+
+                const ALL_FIELD_NAMES: [&'static str; #len] = {
+                    let present: [bool; #len] = [#(<#phantom_generics as #krate::tup_struct::FieldPresence>::PRESENT),*];
+                    let names: [&'static str; #len] = [#(stringify!(#fields)),*];
+                    let mut result = [""; #len];
+                    let mut i = 0;
+                    let mut j = 0;
+                    while i < #len {
+                        if present[i] {
+                            result[j] = names[i];
+                            j += 1;
+                        }
+                        i += 1;
+                    }
+                    result
+                };
+
+                /// The names of the arguments contained in the tup in sorted order.
+                pub const FIELD_NAMES: &'static [&'static str] = {
+                    let names: &[&'static str] = &Self::ALL_FIELD_NAMES;
+                    names.split_at(Self::LEN).0
+                };
+
+                /// Returns the names of the arguments contained in the tup in sorted order.
+                pub fn field_names(&self) -> &'static [&'static str] {
+                    Self::FIELD_NAMES
+                }
+
+                /// Calls the visitor with the name and value of each argument contained in the tup.
+                pub fn for_each_field(&self, mut visitor: impl FnMut(&'static str, &dyn core::fmt::Debug))
+                    #visitor_where_clause
+                {
+                    #(<#phantom_generics as #krate::tup_struct::VisitField<#generics>>::visit(stringify!(#fields), &self.#fields, &mut visitor);)*
+                    let _ = &mut visitor;
+                }
+            }
+        };
+
+        expanded
+    }
+
+    fn to_has_field_impl(&self) -> TokenStream {
+        let Self {
+            generics,
+            fields,
+            phantom_generics,
+            full_generics,
+            ..
+        } = self;
+        let krate = &self.krate;
+        let markers = &self.markers;
+
+        let expanded = quote! {
+            #(
+                impl<#full_generics> #krate::fields::HasField<#markers::#fields> for Tup<#full_generics>
+                    where #phantom_generics: #krate::tup_struct::Present
+                {
+                    type Output = #generics;
+
+                    fn get(&self) -> &#generics {
+                        &self.#fields
+                    }
+
+                    fn get_mut(&mut self) -> &mut #generics {
+                        &mut self.#fields
+                    }
+
+                    fn take(self) -> #generics {
+                        self.#fields
+                    }
+                }
+
+                impl<#full_generics, __P, __T, __Root> #krate::tup_struct::ArgumentOr<#markers::#fields, __P, __T, __Root> for Tup<#full_generics>
+                    where #phantom_generics: #krate::tup_struct::SourceArgument<#generics, __P, __T, __Root>
+                {
+                    fn argument_or(&self, root: &__Root) -> __T {
+                        <#phantom_generics as #krate::tup_struct::SourceArgument<#generics, __P, __T, __Root>>::argument(&self.#fields, root)
+                    }
+                }
+            )*
+        };
+
+        expanded
+    }
+
     fn to_default_impl(&self) -> TokenStream {
         let fields = &self.fields;
         let generics = (0..self.generics.len()).map(|_| syn::parse_str::<syn::Type>("()").unwrap());
-        let phantom_generics = (0..self.phantom_generics.len())
-            .map(|_| syn::parse_str::<syn::Type>("crate::tup_struct::Unused").unwrap());
+        let krate = &self.krate;
+        let phantom_generics =
+            (0..self.phantom_generics.len()).map(|_| quote! {#krate::tup_struct::Unused});
 
         let expanded = quote! {
             impl core::default::Default for Tup<#(#generics,)* #(#phantom_generics),*> {
@@ -110,12 +343,14 @@ impl TupInfo {
             fields,
             phantom_generics,
             full_generics,
+            ..
         } = self;
+        let krate = &self.krate;
 
         let where_clause = match fields.is_empty() {
             true => quote! {},
             false => quote! {
-                where #(#phantom_generics: crate::tup_struct::ConvertToDebugStruct + core::default::Default),*,
+                where #(#phantom_generics: #krate::tup_struct::ConvertToDebugStruct<Self>),*,
                 #(#generics: core::fmt::Debug),*
             },
         };
@@ -126,7 +361,7 @@ impl TupInfo {
             {
                 fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
                     let mut debug_struct = f.debug_struct("tup");
-                    #(crate::tup_struct::ConvertToDebugStruct::convert(<#phantom_generics as core::default::Default>::default(), &mut debug_struct, stringify!(#fields), &self.#fields);)*
+                    #(<#phantom_generics as #krate::tup_struct::ConvertToDebugStruct<Self>>::convert(&mut debug_struct, stringify!(#fields), &self.#fields, self);)*
                     debug_struct.finish()
                 }
             }
@@ -135,53 +370,123 @@ impl TupInfo {
         expanded
     }
 
-    fn to_add_impl(&self) -> TokenStream {
+    fn to_display_impl(&self) -> TokenStream {
         let Self {
             generics,
             fields,
             phantom_generics,
             full_generics,
+            ..
         } = self;
+        let krate = &self.krate;
 
-        let coma = match fields.is_empty() {
+        let where_clause = match fields.is_empty() {
             true => quote! {},
-            false => quote! {,},
+            false => quote! {
+                where #(#phantom_generics: #krate::tup_struct::DisplayField<#generics>),*
+            },
         };
 
-        let rhs_generics_stored: Vec<Ident> =
-            generics.iter().map(|g| format_ident!("RHS{g}")).collect();
-        let rhs_generics = &rhs_generics_stored;
+        let expanded = quote! {
+            impl<#full_generics> core::fmt::Display for Tup<#full_generics>
+                #where_clause
+            {
+                #[allow(unused_mut)]
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    let mut empty = true;
+                    f.write_str("(")?;
+                    #(if <#phantom_generics as #krate::tup_struct::FieldPresence>::PRESENT {
+                        if !empty {
+                            f.write_str(", ")?;
+                        }
+                        empty = false;
+                        f.write_str(stringify!(#fields))?;
+                        f.write_str(": ")?;
+                        <#phantom_generics as #krate::tup_struct::DisplayField<#generics>>::fmt(&self.#fields, f)?;
+                    })*
+                    f.write_str(")")
+                }
+            }
+        };
 
-        let rhs_phantom_generics_stored: Vec<Ident> = phantom_generics
+        expanded
+    }
+
+    /// The generics of the right hand side tup of an operation combining two tups, along with the
+    /// phantoms of both sides without their validators, which do not change how tups combine.
+    fn rhs_generics(&self) -> RhsGenerics {
+        let krate = &self.krate;
+        let rhs_phantom_generics: Vec<Ident> = self
+            .phantom_generics
             .iter()
             .map(|g| format_ident!("RHS{g}"))
             .collect();
-        let rhs_phantom_generics = &rhs_phantom_generics_stored;
+        let without_validator = |phantoms: &[Ident]| -> Vec<TokenStream> {
+            phantoms
+                .iter()
+                .map(|p| quote! {#krate::combine::WithoutValidator<#p>})
+                .collect()
+        };
 
-        let full_rhs_generics = generics
+        let generics: Vec<Ident> = self
+            .generics
             .iter()
-            .chain(phantom_generics.iter())
-            .map(|g| format_ident!("RHS{g}"));
-        let full_rhs_generics = &quote! {#(#full_rhs_generics),*};
+            .map(|g| format_ident!("RHS{g}"))
+            .collect();
+        let full_generics = quote! {#(#generics),* #(,#rhs_phantom_generics)*};
+        let where_clause = match self.fields.is_empty() {
+            true => quote! {},
+            false => {
+                let phantom_generics = &self.phantom_generics;
+                quote! {
+                    where #(#phantom_generics: #krate::combine::RemoveValidator, #rhs_phantom_generics: #krate::combine::RemoveValidator,)*
+                }
+            }
+        };
 
-        let where_clause = match fields.is_empty() {
+        RhsGenerics {
+            phantoms: without_validator(&self.phantom_generics),
+            rhs_phantoms: without_validator(&rhs_phantom_generics),
+            generics,
+            full_generics,
+            where_clause,
+        }
+    }
+
+    fn to_add_impl(&self) -> TokenStream {
+        let Self {
+            generics,
+            fields,
+            full_generics,
+            ..
+        } = self;
+        let krate = &self.krate;
+        let markers = &self.markers;
+
+        let coma = match fields.is_empty() {
             true => quote! {},
-            false => quote! {
-                where #((#generics, #rhs_generics): crate::combine::CanCombine<#phantom_generics, #rhs_phantom_generics>),*
-            },
+            false => quote! {,},
         };
 
+        let RhsGenerics {
+            generics: rhs_generics,
+            phantoms,
+            rhs_phantoms,
+            full_generics: full_rhs_generics,
+            where_clause,
+        } = &self.rhs_generics();
+
         let expanded = quote! {
             impl<#full_generics #coma #full_rhs_generics> core::ops::Add<Tup<#full_rhs_generics>> for Tup<#full_generics>
-                #where_clause
+                #where_clause #((#generics, #rhs_generics): #krate::combine::CanCombine<#phantoms, #rhs_phantoms, #markers::#fields>),*
             {
                 type Output = Tup<
-                    #(<(#generics, #rhs_generics) as crate::combine::CanCombine<#phantom_generics, #rhs_phantom_generics>>::Output),* #coma
-                    #(<(#generics, #rhs_generics) as crate::combine::CanCombine<#phantom_generics, #rhs_phantom_generics>>::PhantomOutput),*>;
+                    #(#krate::combine::Combined<#generics, #rhs_generics, #phantoms, #rhs_phantoms, #markers::#fields>),* #coma
+                    #(#krate::combine::CombinedPhantom<#generics, #rhs_generics, #phantoms, #rhs_phantoms, #markers::#fields>),*>;
 
                 fn add(self, rhs: Tup<#full_rhs_generics>) -> Self::Output{
                     Self::Output {
-                        #(#fields: crate::combine::CanCombine::<#phantom_generics, #rhs_phantom_generics>::combine((self.#fields, rhs.#fields)) ),* #coma
+                        #(#fields: #krate::combine::CanCombine::<#phantoms, #rhs_phantoms, #markers::#fields>::combine((self.#fields, rhs.#fields)) ),* #coma
                         _phantom: core::marker::PhantomData
                     }
                 }
@@ -191,13 +496,163 @@ impl TupInfo {
         expanded
     }
 
-    fn to_into_impl(&self) -> TokenStream {
+    fn to_merge_fn(&self, name: Ident, combine_trait: Ident, output: Ident) -> TokenStream {
+        let Self {
+            generics, fields, ..
+        } = self;
+        let krate = &self.krate;
+        let markers = &self.markers;
+        let phantom_output = format_ident!("{output}Phantom");
+
+        let coma = match fields.is_empty() {
+            true => quote! {},
+            false => quote! {,},
+        };
+
+        let RhsGenerics {
+            generics: rhs_generics,
+            phantoms,
+            rhs_phantoms,
+            full_generics: full_rhs_generics,
+            where_clause,
+        } = &self.rhs_generics();
+
+        quote! {
+            #[allow(clippy::type_complexity)]
+            pub fn #name<#full_rhs_generics>(self, rhs: Tup<#full_rhs_generics>) -> Tup<
+                #(#krate::combine::#output<#generics, #rhs_generics, #phantoms, #rhs_phantoms, #markers::#fields>),* #coma
+                #(#krate::combine::#phantom_output<#generics, #rhs_generics, #phantoms, #rhs_phantoms, #markers::#fields>),*>
+                #where_clause #((#generics, #rhs_generics): #krate::combine::#combine_trait<#phantoms, #rhs_phantoms, #markers::#fields>),*
+            {
+                Tup {
+                    #(#fields: #krate::combine::#combine_trait::<#phantoms, #rhs_phantoms, #markers::#fields>::combine((self.#fields, rhs.#fields)) ),* #coma
+                    _phantom: core::marker::PhantomData
+                }
+            }
+        }
+    }
+
+    fn to_merge_with_fn(&self) -> TokenStream {
+        let Self {
+            generics, fields, ..
+        } = self;
+        let krate = &self.krate;
+        let markers = &self.markers;
+
+        let coma = match fields.is_empty() {
+            true => quote! {},
+            false => quote! {,},
+        };
+
+        let RhsGenerics {
+            generics: rhs_generics,
+            phantoms,
+            rhs_phantoms,
+            full_generics: full_rhs_generics,
+            where_clause,
+        } = &self.rhs_generics();
+
+        quote! {
+            #[allow(clippy::type_complexity)]
+            pub fn merge_with<#full_rhs_generics #coma __C>(self, rhs: Tup<#full_rhs_generics>, combiner: __C) -> Tup<
+                #(#krate::combine::MergedWith<#generics, #rhs_generics, __C, #phantoms, #rhs_phantoms, #markers::#fields>),* #coma
+                #(#krate::combine::MergedWithPhantom<#generics, #rhs_generics, __C, #phantoms, #rhs_phantoms, #markers::#fields>),*>
+                #where_clause #((#generics, #rhs_generics): #krate::combine::CanCombineWith<__C, #phantoms, #rhs_phantoms, #markers::#fields>),*
+            {
+                let _ = &combiner;
+                Tup {
+                    #(#fields: #krate::combine::CanCombineWith::<__C, #phantoms, #rhs_phantoms, #markers::#fields>::combine((self.#fields, rhs.#fields), &combiner) ),* #coma
+                    _phantom: core::marker::PhantomData
+                }
+            }
+        }
+    }
+
+    fn to_merge_impl(&self) -> TokenStream {
+        let full_generics = &self.full_generics;
+
+        let merge_left = self.to_merge_fn(
+            format_ident!("merge_left"),
+            format_ident!("CanCombineLeft"),
+            format_ident!("MergedLeft"),
+        );
+        let merge_disjoint = self.to_merge_fn(
+            format_ident!("merge_disjoint"),
+            format_ident!("CanCombineDisjoint"),
+            format_ident!("MergedDisjoint"),
+        );
+        let merge_with = self.to_merge_with_fn();
+
+        let expanded = quote! {
+            impl<#full_generics> Tup<#full_generics> {
+                /// Merges two tups, giving precedence to the left hand side if both contain an argument.
+                #merge_left
+
+                /// Merges two tups that do not share any argument.
+                #merge_disjoint
+
+                /// Merges two tups, combining the arguments that both contain with the combiner.
+                #merge_with
+            }
+        };
+
+        expanded
+    }
+
+    fn to_sub_impl(&self) -> TokenStream {
         let Self {
             generics,
             fields,
             phantom_generics,
             full_generics,
+            ..
         } = self;
+        let krate = &self.krate;
+        let markers = &self.markers;
+
+        let coma = match fields.is_empty() {
+            true => quote! {},
+            false => quote! {,},
+        };
+
+        // The arguments that are kept also keep their validator.
+        let RhsGenerics {
+            rhs_phantoms,
+            full_generics: full_rhs_generics,
+            where_clause,
+            ..
+        } = &self.rhs_generics();
+
+        let expanded = quote! {
+            impl<#full_generics #coma #full_rhs_generics> core::ops::Sub<Tup<#full_rhs_generics>> for Tup<#full_generics>
+                #where_clause #(#generics: #krate::combine::CanRemove<#phantom_generics, #rhs_phantoms, #markers::#fields>),*
+            {
+                type Output = Tup<
+                    #(#krate::combine::Removed<#generics, #phantom_generics, #rhs_phantoms, #markers::#fields>),* #coma
+                    #(#krate::combine::RemovedPhantom<#generics, #phantom_generics, #rhs_phantoms, #markers::#fields>),*>;
+
+                fn sub(self, _rhs: Tup<#full_rhs_generics>) -> Self::Output{
+                    Self::Output {
+                        #(#fields: #krate::combine::CanRemove::<#phantom_generics, #rhs_phantoms, #markers::#fields>::remove(self.#fields) ),* #coma
+                        _phantom: core::marker::PhantomData
+                    }
+                }
+            }
+        };
+
+        expanded
+    }
+
+    fn to_into_impl(&self, conversion: Conversion) -> TokenStream {
+        let Self {
+            generics,
+            fields,
+            phantom_generics,
+            full_generics,
+            ..
+        } = self;
+        let krate = &self.krate;
+        let markers = &self.markers;
 
         let coma = match fields.is_empty() {
             true => quote! {},
@@ -210,22 +665,112 @@ impl TupInfo {
             .collect();
         let new_phantom_generics = &new_phantom_generics_stored;
 
+        let mode = match conversion {
+            Conversion::Strict => quote! {Strict},
+            Conversion::Lossy => quote! {Lossy},
+            Conversion::Validating => quote! {Validating},
+        };
+
+        let target = quote! {
+            Tup<#(#krate::convert::Converted<#generics, #phantom_generics, #new_phantom_generics, #markers::#fields>,)* #(#new_phantom_generics),*>
+        };
+
+        let where_clause = match fields.is_empty() {
+            true => quote! {},
+            false => quote! {
+                where #(#generics: #krate::convert::CanInto<#phantom_generics, #new_phantom_generics, #markers::#fields>,)*
+                #(#phantom_generics: #krate::convert::ArgumentKind,)*
+                #(#new_phantom_generics: #krate::convert::ArgumentKind,)*
+                #((<#phantom_generics as #krate::convert::ArgumentKind>::Kind, <#new_phantom_generics as #krate::convert::ArgumentKind>::TargetKind): #krate::convert::CheckArgument<#markers::#fields, #krate::convert::#mode>,)*
+                #(#new_phantom_generics: #krate::tup_struct::ResolveDefault<#phantom_generics, Tup<#full_generics>, Default = <#generics as #krate::convert::CanInto<#phantom_generics, #new_phantom_generics, #markers::#fields>>::Default>),*
+            },
+        };
+
+        // The defaults are computed from the source before its arguments are moved.
+        let indices = (0..fields.len()).map(syn::Index::from);
+        let body = match fields.is_empty() {
+            true => quote! {
+                Tup {
+                    _phantom: core::marker::PhantomData
+                }
+            },
+            false => quote! {
+                let defaults = (#(<#new_phantom_generics as #krate::tup_struct::ResolveDefault<#phantom_generics, Tup<#full_generics>>>::resolve(&current),)*);
+                Tup {
+                    #(#fields: #krate::convert::CanInto::<#phantom_generics, #new_phantom_generics, #markers::#fields>::into(current.#fields, defaults.#indices),)*
+                    _phantom: core::marker::PhantomData
+                }
+            },
+        };
+
+        let validate_coma = match fields.is_empty() {
+            true => quote! {where},
+            false => quote! {,},
+        };
+
+        let expanded = match conversion {
+            Conversion::Strict => quote! {
+                impl<#full_generics #coma #(#new_phantom_generics),*> #krate::convert::TupFrom<Tup<#full_generics>> for #target
+                    #where_clause
+                {
+                    fn from_tup(current: Tup<#full_generics>) -> Self {
+                        #body
+                    }
+                }
+            },
+            Conversion::Validating => quote! {
+                impl<#full_generics #coma #(#new_phantom_generics),*> #krate::convert::TryTupFrom<Tup<#full_generics>> for #target
+                    #where_clause #validate_coma
+                    #target: #krate::convert::ValidateTup
+                {
+                    fn try_from_tup(current: Tup<#full_generics>) -> Result<Self, #krate::convert::InvalidArgument> {
+                        let converted: #target = { #body };
+                        #krate::convert::ValidateTup::validate(converted)
+                    }
+                }
+            },
+            Conversion::Lossy => quote! {
+                impl<#full_generics #coma #(#new_phantom_generics),*> #krate::convert::TupProject<#target> for Tup<#full_generics>
+                    #where_clause
+                {
+                    fn project(self) -> #target {
+                        let current = self;
+                        #body
+                    }
+                }
+            },
+        };
+
+        expanded
+    }
+
+    fn to_validate_impl(&self) -> TokenStream {
+        let Self {
+            generics,
+            fields,
+            phantom_generics,
+            full_generics,
+            ..
+        } = self;
+        let krate = &self.krate;
+
         let where_clause = match fields.is_empty() {
             true => quote! {},
             false => quote! {
-                where #(#generics: crate::convert::CanInto<#phantom_generics, #new_phantom_generics>),*
+                where #(#phantom_generics: #krate::tup_struct::ValidateField<#generics>),*
             },
         };
 
         let expanded = quote! {
-            impl<#full_generics #coma #(#new_phantom_generics),*> crate::convert::TupFrom<Tup<#full_generics>> for Tup<#(<#generics as crate::convert::CanInto<#phantom_generics, #new_phantom_generics>>::Output,)* #(#new_phantom_generics),*>
+            impl<#full_generics> #krate::convert::ValidateTup for Tup<#full_generics>
                 #where_clause
             {
-                fn from_tup(current: Tup<#full_generics>) -> Self {
-                    Self {
-                        #(#fields: crate::convert::CanInto::<#phantom_generics, #new_phantom_generics>::into(current.#fields) ,)*
+                fn validate(self) -> Result<Self, #krate::convert::InvalidArgument> {
+                    Ok(Tup {
+                        #(#fields: <#phantom_generics as #krate::tup_struct::ValidateField<#generics>>::validate(self.#fields)
+                            .map_err(|_| #krate::convert::InvalidArgument::new(stringify!(#fields)))?,)*
                         _phantom: core::marker::PhantomData
-                    }
+                    })
                 }
             }
         };
@@ -239,6 +784,7 @@ impl TupInfo {
             fields,
             phantom_generics,
             full_generics,
+            ..
         } = self;
 
         let rhs_phantom_generics_stored: Vec<Ident> = phantom_generics
@@ -275,6 +821,7 @@ impl TupInfo {
             fields,
             phantom_generics,
             full_generics,
+            ..
         } = self;
 
         let rhs_phantom_generics_stored: Vec<Ident> = phantom_generics
@@ -333,6 +880,7 @@ impl TupInfo {
             fields,
             phantom_generics,
             full_generics,
+            ..
         } = self;
 
         let expanded = quote! {
@@ -346,16 +894,136 @@ impl TupInfo {
         expanded
     }
 
+    fn to_serde_impl(&self) -> TokenStream {
+        let Self {
+            generics,
+            fields,
+            phantom_generics,
+            full_generics,
+            ..
+        } = self;
+        let krate = &self.krate;
+        // Downstream crates may not depend on serde themselves.
+        let serde = match self.is_namespace {
+            true => quote! {#krate::serde},
+            false => quote! {serde},
+        };
+
+        let ser_where_clause = match fields.is_empty() {
+            true => quote! {},
+            false => quote! {
+                where #(#phantom_generics: #krate::serialize::SerializeField<#generics>),*
+            },
+        };
+
+        // The Tup holding the arguments read from the map, from which the missing defaults are
+        // computed.
+        let view = quote! {
+            Tup<#(<#phantom_generics as #krate::serialize::DeserializeField<'de, #generics>>::Slot,)* #(<#phantom_generics as #krate::serialize::DeserializeField<'de, #generics>>::SlotPhantom),*>
+        };
+        let de_where_clause = match fields.is_empty() {
+            true => quote! {},
+            false => quote! {
+                where #(#phantom_generics: #krate::serialize::DeserializeField<'de, #generics>,)*
+                #(<#phantom_generics as #krate::serialize::DeserializeField<'de, #generics>>::SlotPhantom: #krate::serialize::FillDefault<#generics, <#phantom_generics as #krate::serialize::DeserializeField<'de, #generics>>::Slot, #view>),*
+            },
+        };
+
+        let slots: Vec<Ident> = (0..fields.len())
+            .map(|count| format_ident!("__field{count}"))
+            .collect();
+        let defaults: Vec<Ident> = (0..fields.len())
+            .map(|count| format_ident!("__default{count}"))
+            .collect();
+        let indices = 0..fields.len();
+
+        let expanded = quote! {
+            impl<#full_generics> #serde::Serialize for Tup<#full_generics>
+                #ser_where_clause
+            {
+                fn serialize<__S: #serde::Serializer>(&self, serializer: __S) -> core::result::Result<__S::Ok, __S::Error> {
+                    let mut map = #serde::Serializer::serialize_map(serializer, core::option::Option::Some(Self::LEN))?;
+                    #(<#phantom_generics as #krate::serialize::SerializeField<#generics>>::serialize(&mut map, stringify!(#fields), &self.#fields)?;)*
+                    #serde::ser::SerializeMap::end(map)
+                }
+            }
+
+            impl<'de, #full_generics> #serde::Deserialize<'de> for Tup<#full_generics>
+                #de_where_clause
+            {
+                fn deserialize<__D: #serde::Deserializer<'de>>(deserializer: __D) -> core::result::Result<Self, __D::Error> {
+                    const FIELDS: &[&str] = &[#(stringify!(#fields)),*];
+
+                    struct TupVisitor<#full_generics>(core::marker::PhantomData<fn() -> Tup<#full_generics>>);
+
+                    impl<'de, #full_generics> #serde::de::Visitor<'de> for TupVisitor<#full_generics>
+                        #de_where_clause
+                    {
+                        type Value = Tup<#full_generics>;
+
+                        fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                            f.write_str("a map of tup arguments")
+                        }
+
+                        #[allow(unused_mut, unused_variables)]
+                        fn visit_map<__M: #serde::de::MapAccess<'de>>(self, mut map: __M) -> core::result::Result<Self::Value, __M::Error> {
+                            #(let mut #slots: core::option::Option<#generics> = core::option::Option::None;)*
+                            while let core::option::Option::Some(index) = #serde::de::MapAccess::next_key_seed(&mut map, #krate::serialize::FieldIndex(FIELDS))? {
+                                match index {
+                                    #(core::option::Option::Some(#indices) => {
+                                        if #slots.is_some() {
+                                            return core::result::Result::Err(<__M::Error as #serde::de::Error>::duplicate_field(stringify!(#fields)));
+                                        }
+                                        #slots = <#phantom_generics as #krate::serialize::DeserializeField<'de, #generics>>::deserialize(&mut map)?;
+                                    })*
+                                    _ => {
+                                        #serde::de::MapAccess::next_value::<#serde::de::IgnoredAny>(&mut map)?;
+                                    }
+                                }
+                            }
+                            let view: #view = Tup {
+                                #(#fields: <#phantom_generics as #krate::serialize::DeserializeField<'de, #generics>>::slot(#slots, stringify!(#fields))?,)*
+                                _phantom: core::marker::PhantomData
+                            };
+                            #(let #defaults = <<#phantom_generics as #krate::serialize::DeserializeField<'de, #generics>>::SlotPhantom as #krate::serialize::FillDefault<#generics, _, #view>>::fill(&view.#fields, &view);)*
+                            core::result::Result::Ok(Tup {
+                                #(#fields: <#phantom_generics as #krate::serialize::DeserializeField<'de, #generics>>::finish(view.#fields, #defaults, stringify!(#fields))?,)*
+                                _phantom: core::marker::PhantomData
+                            })
+                        }
+                    }
+
+                    #serde::Deserializer::deserialize_map(deserializer, TupVisitor(core::marker::PhantomData))
+                }
+            }
+        };
+
+        expanded
+    }
+
     pub fn to_token_stream(&self) -> TokenStream {
         let mut result = self.to_def();
         result.extend(self.to_new_impl());
+        result.extend(self.to_builder_impl());
+        result.extend(self.to_reflection_impl());
+        result.extend(self.to_has_field_impl());
         result.extend(self.to_default_impl());
         result.extend(self.to_debug_impl());
+        result.extend(self.to_display_impl());
         result.extend(self.to_add_impl());
-        result.extend(self.to_into_impl());
+        result.extend(self.to_merge_impl());
+        result.extend(self.to_sub_impl());
+        result.extend(self.to_into_impl(Conversion::Strict));
+        result.extend(self.to_into_impl(Conversion::Lossy));
+        result.extend(self.to_into_impl(Conversion::Validating));
+        result.extend(self.to_validate_impl());
         result.extend(self.to_eq_impl());
         result.extend(self.to_ord_impl());
         result.extend(self.to_hash_impl());
+        // Enabled by the serde feature of named-tup.
+        if cfg!(feature = "serde") {
+            result.extend(self.to_serde_impl());
+        }
         result
     }
 }
@@ -0,0 +1,66 @@
+use proc_macro2::{Ident, TokenStream};
+use syn::parse::{Parse, ParseStream, Result};
+use syn::visit_mut::VisitMut;
+use syn::{Attribute, Token, Type, Visibility};
+
+use crate::tup_default::TupDefaultReplace;
+
+/// A named tup type such as `pub OpenOptions = Tup!(read: bool = true)`.
+pub struct TupTypeAlias {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    ty: Type,
+}
+
+pub struct TupTypeAliases(Vec<TupTypeAlias>);
+
+impl Parse for TupTypeAlias {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let ty = input.parse()?;
+        Ok(TupTypeAlias {
+            attrs,
+            vis,
+            name,
+            ty,
+        })
+    }
+}
+
+impl Parse for TupTypeAliases {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let aliases = input.parse_terminated(TupTypeAlias::parse, Token![;])?;
+        Ok(TupTypeAliases(aliases.into_iter().collect()))
+    }
+}
+
+impl TupTypeAliases {
+    /// Generates a type alias for each named tup type, along with the structs of its defaults
+    /// which have the same visibility so that the alias can be used wherever it is visible.
+    pub fn into_token_stream(self) -> TokenStream {
+        let mut expanded = TokenStream::new();
+        for alias in self.0 {
+            let TupTypeAlias {
+                attrs,
+                vis,
+                name,
+                mut ty,
+            } = alias;
+            let mut replace = TupDefaultReplace::new(&quote! {#vis #name});
+            replace.visibility = vis.clone();
+            replace.visit_type_mut(&mut ty);
+
+            let struct_invocations = replace.into_token_stream();
+            expanded.extend(quote! {
+                #struct_invocations
+                #(#attrs)*
+                #vis type #name = #ty;
+            });
+        }
+        expanded
+    }
+}
@@ -0,0 +1,15 @@
+extern crate named_tup as nt;
+
+use named_tup::{htup as h, tup as t, Tup as T};
+
+fn farm() -> T!(cows: u8) {
+    t!(cows: 4)
+}
+
+fn qualified() -> nt::Tup!(bulls: u8) {
+    ::named_tup::tup!(bulls: 2) + nt::tup!(hens: 3)
+}
+
+fn listed() -> named_tup::HTup!(geese: u8) {
+    h!(geese: 1)
+}
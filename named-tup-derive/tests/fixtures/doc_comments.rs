@@ -0,0 +1,17 @@
+//! ```
+//! # use named_tup::tup;
+//! let farm = tup!(cows: 4);
+//! ```
+
+/// An unclosed code block.
+/// ```
+/// tup!(unclosed: 1)
+fn unclosed() {}
+
+/// Not code: tup!(prose: 1)
+///
+/// ```rust
+/// # let farm = named_tup::tup!(hidden: 1);
+/// let farm: Tup!(documented: u8) = tup!(documented: 1);
+/// ```
+fn documented() {}
@@ -0,0 +1,3 @@
+fn invalid() -> {
+    tup!(recovered: 1)
+}
@@ -0,0 +1,12 @@
+use named_tup::tup;
+
+macro_rules! farm {
+    ($count:expr, $name:ident) => {
+        tup!(count: $count, farmer: $name, $name)
+    };
+}
+
+fn nested() {
+    let farms = vec![tup!(cows: 1), named_tup::tup!(bulls: 2)];
+    assert_eq!(farms.len(), 2, "{:?}", tup!(dogs: 3));
+}
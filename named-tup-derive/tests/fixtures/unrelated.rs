@@ -0,0 +1,10 @@
+use other::tup;
+use other::Type as Tup;
+
+fn unrelated() {
+    tup!(ignored: 1);
+    let _: Tup!(ignored: u8);
+    other::tup!(ignored: 1);
+    vec![other::Tup!(ignored: u8)];
+    named_tup::tup!(found: 1);
+}
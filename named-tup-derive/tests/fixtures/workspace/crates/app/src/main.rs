@@ -0,0 +1,5 @@
+named_tup::tup_namespace!();
+
+fn main() {
+    let _ = named_tup::tup!(host: "localhost", port: 80);
+}
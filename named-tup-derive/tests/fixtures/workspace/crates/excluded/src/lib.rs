@@ -0,0 +1 @@
+named_tup::tup_namespace!();
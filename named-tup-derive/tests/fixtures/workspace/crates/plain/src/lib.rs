@@ -0,0 +1,3 @@
+pub fn cows() -> named_tup::Tup!(cows: u8) {
+    named_tup::tup!(cows: 3)
+}
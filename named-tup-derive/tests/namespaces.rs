@@ -0,0 +1,60 @@
+use std::path::{Path, PathBuf};
+
+#[allow(dead_code)]
+#[path = "../namespaces.rs"]
+mod namespaces;
+#[allow(dead_code)]
+#[path = "../tup_finder.rs"]
+mod tup_finder;
+
+fn workspace() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/workspace")
+}
+
+#[test]
+fn members_and_path_dependencies() {
+    let workspace = workspace();
+    let namespaces = namespaces::find_namespaces([workspace.clone()]);
+    let found: Vec<(&Path, &str, &[String])> = namespaces
+        .iter()
+        .map(|n| {
+            (
+                n.manifest_dir.as_path(),
+                n.krate.as_str(),
+                &n.identifiers[..],
+            )
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (
+                workspace.join("crates/app").as_path(),
+                "app",
+                &["host".to_string(), "port".to_string()][..]
+            ),
+            (
+                workspace.join("helper").as_path(),
+                "helper_lib",
+                &["port".to_string(), "retries".to_string()][..]
+            ),
+        ]
+    );
+    assert_eq!(
+        namespaces[0].dependencies,
+        [("renamed".to_string(), workspace.join("helper"))]
+    );
+}
+
+#[test]
+fn generated_list() {
+    let namespaces = namespaces::find_namespaces([workspace().join("crates/app/./../app")]);
+    let app = workspace().join("crates/app");
+    let helper = workspace().join("helper");
+    assert_eq!(
+        namespaces::to_string(&namespaces),
+        format!(
+            r#"&[Namespace {{ manifest_dir: {app:?}, krate: "app", identifiers: &["host", "port"], dependencies: &[("renamed", &["port", "retries"])] }}, Namespace {{ manifest_dir: {helper:?}, krate: "helper_lib", identifiers: &["port", "retries"], dependencies: &[] }}]"#
+        )
+    );
+}
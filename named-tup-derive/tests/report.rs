@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use serde_json::json;
+
+#[allow(dead_code)]
+#[path = "../report.rs"]
+mod report;
+
+use report::{Identifiers, Origin};
+
+#[test]
+fn json_shape() {
+    let mut identifiers = Identifiers::default();
+    identifiers.insert(
+        "cows".into(),
+        Origin::Source {
+            package: Some("farm".into()),
+            file: PathBuf::from("src/main.rs"),
+            line: 3,
+        },
+    );
+    identifiers.insert("cows".into(), Origin::Environment);
+    identifiers.insert(
+        "bulls".into(),
+        Origin::Source {
+            package: None,
+            file: PathBuf::from("src/lib.rs"),
+            line: 1,
+        },
+    );
+    identifiers.insert(
+        "hens".into(),
+        Origin::Arguments {
+            package: "farm".into(),
+        },
+    );
+    identifiers.insert(
+        "dogs".into(),
+        Origin::Config {
+            file: PathBuf::from("named-tup.toml"),
+        },
+    );
+    identifiers.insert("roosters".into(), Origin::DevIdents);
+
+    let report: serde_json::Value = serde_json::from_str(&identifiers.to_json()).unwrap();
+    assert_eq!(
+        report,
+        json!({
+            "bulls": [{"kind": "source", "package": null, "file": "src/lib.rs", "line": 1}],
+            "cows": [
+                {"kind": "source", "package": "farm", "file": "src/main.rs", "line": 3},
+                {"kind": "environment"}
+            ],
+            "dogs": [{"kind": "config", "file": "named-tup.toml"}],
+            "hens": [{"kind": "arguments", "package": "farm"}],
+            "roosters": [{"kind": "dev_idents"}]
+        })
+    );
+}
+
+#[test]
+fn json_escaping() {
+    let file = "dir \"quoted\"\\back\nslash\t\u{1}é.rs";
+    let mut identifiers = Identifiers::default();
+    identifiers.insert(
+        "cows".into(),
+        Origin::Config {
+            file: PathBuf::from(file),
+        },
+    );
+
+    let report: serde_json::Value = serde_json::from_str(&identifiers.to_json()).unwrap();
+    assert_eq!(report, json!({"cows": [{"kind": "config", "file": file}]}));
+}
+
+#[test]
+fn empty_json() {
+    let report: serde_json::Value =
+        serde_json::from_str(&Identifiers::default().to_json()).unwrap();
+    assert_eq!(report, json!({}));
+}
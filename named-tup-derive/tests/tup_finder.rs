@@ -0,0 +1,80 @@
+use std::path::{Path, PathBuf};
+
+#[allow(dead_code)]
+#[path = "../tup_finder.rs"]
+mod tup_finder;
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+/// The identifiers found in a fixture along with the line they were found on.
+fn find(name: &str) -> Vec<(String, usize)> {
+    let mut found = vec![];
+    tup_finder::get_all_identifiers(&fixture(name), &mut found).unwrap();
+    found
+}
+
+fn expected(identifiers: &[(&str, usize)]) -> Vec<(String, usize)> {
+    identifiers
+        .iter()
+        .map(|(name, line)| (name.to_string(), *line))
+        .collect()
+}
+
+#[test]
+fn aliases_and_qualified_paths() {
+    assert_eq!(
+        find("alias.rs"),
+        expected(&[
+            ("cows", 5),
+            ("cows", 6),
+            ("bulls", 9),
+            ("bulls", 10),
+            ("hens", 10),
+            ("geese", 13),
+            ("geese", 14)
+        ])
+    );
+}
+
+#[test]
+fn unrelated_macros() {
+    assert_eq!(find("unrelated.rs"), expected(&[("found", 9)]));
+}
+
+#[test]
+fn macro_rules_and_nested_macros() {
+    assert_eq!(
+        find("macro_rules.rs"),
+        expected(&[
+            ("count", 5),
+            ("farmer", 5),
+            ("cows", 10),
+            ("bulls", 10),
+            ("dogs", 11)
+        ])
+    );
+}
+
+#[test]
+fn doc_comments() {
+    assert_eq!(
+        find("doc_comments.rs"),
+        expected(&[
+            ("cows", 3),
+            ("hidden", 14),
+            ("documented", 15),
+            ("documented", 15)
+        ])
+    );
+}
+
+#[test]
+fn invalid_file() {
+    let mut found = vec![];
+    assert!(tup_finder::get_all_identifiers(&fixture("invalid.rs"), &mut found).is_err());
+    assert_eq!(found, expected(&[("recovered", 2)]));
+}
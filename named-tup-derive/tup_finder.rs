@@ -1,11 +1,16 @@
 use std::collections::HashSet;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
+use proc_macro2::{Delimiter, Ident, Spacing, TokenStream, TokenTree};
 use syn::parse::{Parse, ParseStream};
 use syn::visit::Visit;
-use syn::{visit, Macro, PathArguments, Result, Token};
+use syn::{
+    visit, Attribute, Expr, ItemExternCrate, ItemUse, Lit, Macro, Meta, Result, Token, UseTree,
+};
 
+#[allow(dead_code)]
 mod tup_element {
     include! {"src/tup_element.rs"}
 }
@@ -36,43 +41,332 @@ impl Parse for TupTypeInvocation {
     }
 }
 
-struct TupFinder<'a>(&'a mut HashSet<String>);
+/// The name of the named-tup crate, paths starting with any other crate cannot refer to its macros.
+const CRATE_NAME: &str = "named_tup";
 
-impl<'a> Visit<'_> for TupFinder<'a> {
-    fn visit_macro(&mut self, mac: &Macro) {
-        if mac.path.leading_colon.is_none() && mac.path.segments.len() == 1 {
-            let seg = mac.path.segments.first().unwrap();
-            if let PathArguments::None = seg.arguments {
-                if seg.ident == "tup" {
-                    let mac = syn::parse2::<TupElementInvocation>(mac.tokens.clone());
-                    if let Ok(mac) = mac {
-                        for tuple in mac.0 {
-                            let ident = tuple.name.to_string();
-                            self.0.insert(ident);
+/// The macros of named-tup that produce a tup and a tup type.
+const ELEMENT_MACROS: [&str; 2] = ["tup", "htup"];
+const TYPE_MACROS: [&str; 2] = ["Tup", "HTup"];
+
+/// Finds the names that the tup and Tup macros are imported as in a file.
+#[derive(Default)]
+struct AliasFinder {
+    /// The names of the named-tup crate, including the ones given by `extern crate` renames.
+    crate_names: HashSet<String>,
+    element_macros: HashSet<String>,
+    type_macros: HashSet<String>,
+    /// The macro names that are imported from other crates, so do not refer to the tup macros.
+    shadowed: HashSet<String>,
+}
+
+impl AliasFinder {
+    fn new() -> Self {
+        AliasFinder {
+            crate_names: HashSet::from([CRATE_NAME.to_string()]),
+            ..Default::default()
+        }
+    }
+
+    /// `from_crate` tells whether the path leading to the tree starts with the named-tup crate.
+    fn visit_use_tree_alias(&mut self, tree: &UseTree, from_crate: bool) {
+        match tree {
+            UseTree::Path(path) => {
+                let from_crate = from_crate || self.crate_names.contains(&path.ident.to_string());
+                self.visit_use_tree_alias(&path.tree, from_crate)
+            }
+            UseTree::Rename(rename)
+                if from_crate && ELEMENT_MACROS.contains(&&*rename.ident.to_string()) =>
+            {
+                self.element_macros.insert(rename.rename.to_string());
+            }
+            UseTree::Rename(rename)
+                if from_crate && TYPE_MACROS.contains(&&*rename.ident.to_string()) =>
+            {
+                self.type_macros.insert(rename.rename.to_string());
+            }
+            UseTree::Name(name) if !from_crate => {
+                self.shadowed.insert(name.ident.to_string());
+            }
+            UseTree::Rename(rename) if !from_crate => {
+                self.shadowed.insert(rename.rename.to_string());
+            }
+            UseTree::Group(group) => group
+                .items
+                .iter()
+                .for_each(|tree| self.visit_use_tree_alias(tree, from_crate)),
+            _ => {}
+        }
+    }
+}
+
+impl Visit<'_> for AliasFinder {
+    fn visit_item_extern_crate(&mut self, item: &ItemExternCrate) {
+        if item.ident == CRATE_NAME {
+            if let Some((_, rename)) = &item.rename {
+                self.crate_names.insert(rename.to_string());
+            }
+        }
+    }
+
+    fn visit_item_use(&mut self, item: &ItemUse) {
+        self.visit_use_tree_alias(&item.tree, false);
+    }
+}
+
+struct TupFinder<'a> {
+    /// Each identifier found along with the line it was found on.
+    identifiers: &'a mut Vec<(String, usize)>,
+    crate_names: HashSet<String>,
+    element_macros: HashSet<String>,
+    type_macros: HashSet<String>,
+    /// The code of the doc comment code block currently being read, as each line of a doc
+    /// comment is a separate attribute.
+    doc_code: Option<String>,
+    /// The last line of the previous doc comment attribute, a doc comment that does not start
+    /// right after it belongs to another doc block.
+    doc_end_line: usize,
+    /// The line that the tokens being visited start on, as the tokens of doc comment code
+    /// blocks have their own line numbers.
+    line_offset: usize,
+}
+
+impl<'a> TupFinder<'a> {
+    fn new(identifiers: &'a mut Vec<(String, usize)>, aliases: AliasFinder) -> Self {
+        let unshadowed = |names: [&str; 2]| {
+            names
+                .into_iter()
+                .filter(|name| !aliases.shadowed.contains(*name))
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        let mut element_macros = aliases.element_macros.clone();
+        element_macros.extend(unshadowed(ELEMENT_MACROS));
+        let mut type_macros = aliases.type_macros.clone();
+        type_macros.extend(unshadowed(TYPE_MACROS));
+        TupFinder {
+            identifiers,
+            crate_names: aliases.crate_names,
+            element_macros,
+            type_macros,
+            doc_code: None,
+            doc_end_line: 0,
+            line_offset: 0,
+        }
+    }
+
+    /// Adds the invocation if the path of the macro refers to one of the tup macros. `segments`
+    /// are the segments of the path, leading `::` excluded.
+    fn add_invocation(&mut self, segments: &[String], tokens: TokenStream) {
+        let (is_element, is_type) = match segments {
+            [name] => (
+                self.element_macros.contains(name),
+                self.type_macros.contains(name),
+            ),
+            [krate, .., name] if self.crate_names.contains(krate) => (
+                ELEMENT_MACROS.contains(&name.as_str()),
+                TYPE_MACROS.contains(&name.as_str()),
+            ),
+            _ => (false, false),
+        };
+        if is_element {
+            self.add_element_invocation(tokens);
+        } else if is_type {
+            self.add_type_invocation(tokens);
+        }
+    }
+
+    fn add(&mut self, name: &Ident) {
+        let line = name.span().start().line + self.line_offset;
+        self.identifiers.push((name.to_string(), line));
+    }
+
+    fn add_element_invocation(&mut self, tokens: TokenStream) {
+        match syn::parse2::<TupElementInvocation>(tokens.clone()) {
+            Ok(mac) => {
+                for tuple in mac.0 {
+                    self.add(&tuple.name);
+                }
+            }
+            Err(_) => self.add_lenient_invocation(tokens, false),
+        }
+    }
+
+    fn add_type_invocation(&mut self, tokens: TokenStream) {
+        match syn::parse2::<TupTypeInvocation>(tokens.clone()) {
+            Ok(mac) => {
+                for tuple in mac.0 {
+                    self.add(&tuple.name);
+                }
+            }
+            Err(_) => self.add_lenient_invocation(tokens, true),
+        }
+    }
+
+    /// Used for invocations that are not valid Rust by themselves, such as ones inside
+    /// `macro_rules!` bodies that contain metavariables. Each comma separated element that
+    /// starts with `name:` (or is only a name for the tup macro) is assumed to be an argument.
+    fn add_lenient_invocation(&mut self, tokens: TokenStream, is_type: bool) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        for element in tokens.split(|t| matches!(t, TokenTree::Punct(p) if p.as_char() == ',')) {
+            let name = match element.first() {
+                Some(TokenTree::Ident(name)) => name,
+                _ => continue,
+            };
+            let is_argument = match element.get(1) {
+                Some(TokenTree::Punct(p)) => p.as_char() == ':' && p.spacing() == Spacing::Alone,
+                None => !is_type,
+                _ => false,
+            };
+            if is_argument {
+                self.add(name);
+            }
+        }
+    }
+
+    /// Looks for invocations inside the tokens of other macros, which syn does not parse.
+    fn visit_tokens(&mut self, tokens: TokenStream) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        for (i, token) in tokens.iter().enumerate() {
+            match token {
+                TokenTree::Ident(_) => {
+                    if let (Some(TokenTree::Punct(bang)), Some(TokenTree::Group(group))) =
+                        (tokens.get(i + 1), tokens.get(i + 2))
+                    {
+                        if bang.as_char() == '!' && group.delimiter() != Delimiter::None {
+                            let segments = path_segments(&tokens[..=i]);
+                            self.add_invocation(&segments, group.stream());
                         }
                     }
-                } else if seg.ident == "Tup" {
-                    let mac = syn::parse2::<TupTypeInvocation>(mac.tokens.clone());
+                }
+                TokenTree::Group(group) => self.visit_tokens(group.stream()),
+                _ => {}
+            }
+        }
+    }
 
-                    if let Ok(mac) = mac {
-                        for tuple in mac.0 {
-                            let ident = tuple.name.to_string();
-                            self.0.insert(ident);
+    /// Looks for invocations inside the code blocks of doc comments.
+    fn visit_doc(&mut self, doc: &str, doc_line: usize) {
+        for (i, line) in doc.lines().enumerate() {
+            let line = line.trim();
+            match (line.starts_with("```"), &mut self.doc_code) {
+                (true, None) => {
+                    self.doc_code = Some(String::new());
+                    self.line_offset = doc_line + i;
+                }
+                (true, Some(_)) => {
+                    let code = self.doc_code.take().unwrap();
+                    if let Ok(tokens) = TokenStream::from_str(&code) {
+                        self.visit_tokens(tokens);
+                    }
+                    self.line_offset = 0;
+                }
+                (false, Some(code)) => {
+                    // Hidden lines of doc tests start with a `#`.
+                    let line = match line.strip_prefix('#') {
+                        Some(hidden) if hidden.is_empty() || hidden.starts_with(' ') => hidden,
+                        _ => line,
+                    };
+                    code.push_str(line);
+                    code.push('\n');
+                }
+                (false, None) => {}
+            }
+        }
+    }
+}
+
+impl<'a> Visit<'_> for TupFinder<'a> {
+    fn visit_attribute(&mut self, attr: &Attribute) {
+        if let Meta::NameValue(meta) = &attr.meta {
+            if meta.path.is_ident("doc") {
+                if let Expr::Lit(expr) = &meta.value {
+                    if let Lit::Str(doc) = &expr.lit {
+                        let span = doc.span();
+                        // An unclosed code block does not carry over to the next doc block.
+                        if span.start().line > self.doc_end_line + 1 {
+                            self.doc_code = None;
+                            self.line_offset = 0;
                         }
+                        self.doc_end_line = span.end().line;
+                        self.visit_doc(&doc.value(), span.start().line);
                     }
                 }
             }
         }
+        visit::visit_attribute(self, attr);
+    }
+
+    fn visit_macro(&mut self, mac: &Macro) {
+        let segments: Vec<String> = mac
+            .path
+            .segments
+            .iter()
+            .map(|seg| seg.ident.to_string())
+            .collect();
+        self.add_invocation(&segments, mac.tokens.clone());
+        self.visit_tokens(mac.tokens.clone());
         visit::visit_macro(self, mac);
     }
 }
 
-pub fn get_all_identifiers(file_path: &Path, all_identifiers: &mut HashSet<String>) {
+/// Returns the segments of the path ending with the last token, leading `::` excluded.
+fn path_segments(tokens: &[TokenTree]) -> Vec<String> {
+    let mut segments = vec![];
+    let mut end = tokens.len();
+    while let Some(TokenTree::Ident(segment)) = end.checked_sub(1).map(|i| &tokens[i]) {
+        segments.push(segment.to_string());
+        end -= 1;
+        match (end.checked_sub(2).map(|i| &tokens[i..end]), end) {
+            (Some([TokenTree::Punct(first), TokenTree::Punct(second)]), _)
+                if first.as_char() == ':'
+                    && first.spacing() == Spacing::Joint
+                    && second.as_char() == ':' =>
+            {
+                end -= 2
+            }
+            _ => break,
+        }
+    }
+    segments.reverse();
+    segments
+}
+
+/// Finds all the identifiers used in a file along with the line they are used on.
+///
+/// If the file cannot be parsed its tokens are still searched and the parse error is returned.
+pub fn get_all_identifiers(
+    file_path: &Path,
+    all_identifiers: &mut Vec<(String, usize)>,
+) -> Result<()> {
     let code = fs::read_to_string(file_path)
         .unwrap_or_else(|_| panic!("Could not read to file at: {file_path:?}"));
 
-    let syntax = syn::parse_file(&code);
-    if let Ok(syntax) = syntax {
-        TupFinder(all_identifiers).visit_file(&syntax);
+    match syn::parse_file(&code) {
+        Ok(syntax) => {
+            let mut aliases = AliasFinder::new();
+            aliases.visit_file(&syntax);
+            TupFinder::new(all_identifiers, aliases).visit_file(&syntax);
+            Ok(())
+        }
+        Err(err) => {
+            if let Ok(tokens) = TokenStream::from_str(&code) {
+                TupFinder::new(all_identifiers, AliasFinder::new()).visit_tokens(tokens);
+            }
+            Err(err)
+        }
+    }
+}
+
+/// Adds the paths of the Rust files in a directory and its subdirectories.
+pub fn scan_rs_paths(dir: &Path, rs_paths: &mut Vec<PathBuf>) {
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                scan_rs_paths(&path, rs_paths);
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                rs_paths.push(path);
+            }
+        }
     }
 }
@@ -1,13 +1,43 @@
-use crate::tup_struct::{TupDefault, Unused, Used};
+use core::ops::Add;
 
-/// Defines how two Tup's arguments can be added together
-pub trait CanCombine<P1, P2> {
+use crate::tup_struct::{TupDefault, Unused, Unvalidated, Used, Validated};
+
+/// Removes the validator of an argument, as validators do not change how tups are combined. The
+/// traits below are implemented for the phantoms of arguments without validators.
+pub trait RemoveValidator {
+    type Phantom;
+}
+
+impl<P: Unvalidated> RemoveValidator for P {
+    type Phantom = P;
+}
+
+impl<P: Unvalidated, V> RemoveValidator for Validated<P, V> {
+    type Phantom = P;
+}
+
+/// The phantom of an argument without its validator.
+pub type WithoutValidator<P> = <P as RemoveValidator>::Phantom;
+
+/// Defines how two Tup's arguments can be added together, `F` being the marker type of the argument
+#[diagnostic::on_unimplemented(
+    message = "the tup argument `{F}` cannot be added",
+    label = "the argument `{F}` is set in both tups with different types",
+    note = "an argument contained in both tups must have the same type in each"
+)]
+pub trait CanCombine<P1, P2, F> {
     type Output;
     type PhantomOutput;
     fn combine(self) -> Self::Output;
 }
 
-impl<T> CanCombine<Used, Used> for (T, T) {
+/// The type of an argument after adding two tups.
+pub type Combined<T1, T2, P1, P2, F> = <(T1, T2) as CanCombine<P1, P2, F>>::Output;
+
+/// Whether an argument is used, unused or defaulted after adding two tups.
+pub type CombinedPhantom<T1, T2, P1, P2, F> = <(T1, T2) as CanCombine<P1, P2, F>>::PhantomOutput;
+
+impl<T, F> CanCombine<Used, Used, F> for (T, T) {
     type Output = T;
     type PhantomOutput = Used;
     fn combine(self) -> T {
@@ -15,7 +45,7 @@ impl<T> CanCombine<Used, Used> for (T, T) {
     }
 }
 
-impl<T> CanCombine<Used, Unused> for (T, ()) {
+impl<T, F> CanCombine<Used, Unused, F> for (T, ()) {
     type Output = T;
     type PhantomOutput = Used;
     fn combine(self) -> T {
@@ -23,7 +53,7 @@ impl<T> CanCombine<Used, Unused> for (T, ()) {
     }
 }
 
-impl<T> CanCombine<Unused, Used> for ((), T) {
+impl<T, F> CanCombine<Unused, Used, F> for ((), T) {
     type Output = T;
     type PhantomOutput = Used;
     fn combine(self) -> T {
@@ -31,13 +61,13 @@ impl<T> CanCombine<Unused, Used> for ((), T) {
     }
 }
 
-impl CanCombine<Unused, Unused> for ((), ()) {
+impl<F> CanCombine<Unused, Unused, F> for ((), ()) {
     type Output = ();
     type PhantomOutput = Unused;
     fn combine(self) {}
 }
 
-impl<T, D: TupDefault> CanCombine<Used, D> for (T, T) {
+impl<T, D: TupDefault, F> CanCombine<Used, D, F> for (T, T) {
     type Output = T;
     type PhantomOutput = Used;
     fn combine(self) -> T {
@@ -45,7 +75,7 @@ impl<T, D: TupDefault> CanCombine<Used, D> for (T, T) {
     }
 }
 
-impl<T, D1: TupDefault, D2: TupDefault> CanCombine<D1, D2> for (T, T) {
+impl<T, D1: TupDefault, D2: TupDefault, F> CanCombine<D1, D2, F> for (T, T) {
     type Output = T;
     type PhantomOutput = D2;
     fn combine(self) -> T {
@@ -53,7 +83,149 @@ impl<T, D1: TupDefault, D2: TupDefault> CanCombine<D1, D2> for (T, T) {
     }
 }
 
-impl<T, D: TupDefault> CanCombine<D, Used> for (T, T) {
+impl<T, D: TupDefault, F> CanCombine<D, Used, F> for (T, T) {
+    type Output = T;
+    type PhantomOutput = Used;
+    fn combine(self) -> T {
+        self.1
+    }
+}
+
+impl<T, D: TupDefault, F> CanCombine<Unused, D, F> for ((), T) {
+    type Output = T;
+    type PhantomOutput = D;
+    fn combine(self) -> T {
+        self.1
+    }
+}
+
+impl<T, D: TupDefault, F> CanCombine<D, Unused, F> for (T, ()) {
+    type Output = T;
+    type PhantomOutput = D;
+    fn combine(self) -> T {
+        self.0
+    }
+}
+
+/// Defines how two Tup's arguments can be merged when precedence is given to the left hand side,
+/// `F` being the marker type of the argument
+#[diagnostic::on_unimplemented(
+    message = "the tup argument `{F}` cannot be merged",
+    label = "the argument `{F}` is set in both tups with different types",
+    note = "an argument contained in both tups must have the same type in each"
+)]
+pub trait CanCombineLeft<P1, P2, F> {
+    type Output;
+    type PhantomOutput;
+    fn combine(self) -> Self::Output;
+}
+
+/// The type of an argument after merging two tups with `merge_left`.
+pub type MergedLeft<T1, T2, P1, P2, F> = <(T1, T2) as CanCombineLeft<P1, P2, F>>::Output;
+
+/// Whether an argument is used, unused or defaulted after merging two tups with `merge_left`.
+pub type MergedLeftPhantom<T1, T2, P1, P2, F> =
+    <(T1, T2) as CanCombineLeft<P1, P2, F>>::PhantomOutput;
+
+impl<T, F> CanCombineLeft<Used, Used, F> for (T, T) {
+    type Output = T;
+    type PhantomOutput = Used;
+    fn combine(self) -> T {
+        self.0
+    }
+}
+
+impl<T, F> CanCombineLeft<Used, Unused, F> for (T, ()) {
+    type Output = T;
+    type PhantomOutput = Used;
+    fn combine(self) -> T {
+        self.0
+    }
+}
+
+impl<T, F> CanCombineLeft<Unused, Used, F> for ((), T) {
+    type Output = T;
+    type PhantomOutput = Used;
+    fn combine(self) -> T {
+        self.1
+    }
+}
+
+impl<F> CanCombineLeft<Unused, Unused, F> for ((), ()) {
+    type Output = ();
+    type PhantomOutput = Unused;
+    fn combine(self) {}
+}
+
+impl<T, D: TupDefault, F> CanCombineLeft<Used, D, F> for (T, T) {
+    type Output = T;
+    type PhantomOutput = Used;
+    fn combine(self) -> T {
+        self.0
+    }
+}
+
+impl<T, D1: TupDefault, D2: TupDefault, F> CanCombineLeft<D1, D2, F> for (T, T) {
+    type Output = T;
+    type PhantomOutput = D1;
+    fn combine(self) -> T {
+        self.0
+    }
+}
+
+impl<T, D: TupDefault, F> CanCombineLeft<D, Used, F> for (T, T) {
+    type Output = T;
+    type PhantomOutput = Used;
+    fn combine(self) -> T {
+        self.1
+    }
+}
+
+impl<T, D: TupDefault, F> CanCombineLeft<Unused, D, F> for ((), T) {
+    type Output = T;
+    type PhantomOutput = D;
+    fn combine(self) -> T {
+        self.1
+    }
+}
+
+impl<T, D: TupDefault, F> CanCombineLeft<D, Unused, F> for (T, ()) {
+    type Output = T;
+    type PhantomOutput = D;
+    fn combine(self) -> T {
+        self.0
+    }
+}
+
+/// Defines how two Tup's arguments can be merged when at most one side contains the argument, `F`
+/// being the marker type of the argument
+#[diagnostic::on_unimplemented(
+    message = "the tup argument `{F}` is contained in both tups",
+    label = "the argument `{F}` cannot be merged disjointly",
+    note = "use `merge_left` or `merge_with` to merge tups that share arguments"
+)]
+pub trait CanCombineDisjoint<P1, P2, F> {
+    type Output;
+    type PhantomOutput;
+    fn combine(self) -> Self::Output;
+}
+
+/// The type of an argument after merging two tups with `merge_disjoint`.
+pub type MergedDisjoint<T1, T2, P1, P2, F> = <(T1, T2) as CanCombineDisjoint<P1, P2, F>>::Output;
+
+/// Whether an argument is used, unused or defaulted after merging two tups with `merge_disjoint`.
+pub type MergedDisjointPhantom<T1, T2, P1, P2, F> =
+    <(T1, T2) as CanCombineDisjoint<P1, P2, F>>::PhantomOutput;
+
+impl<T, F> CanCombineDisjoint<Used, Unused, F> for (T, ()) {
+    type Output = T;
+    type PhantomOutput = Used;
+    fn combine(self) -> T {
+        self.0
+    }
+}
+
+impl<T, F> CanCombineDisjoint<Unused, Used, F> for ((), T) {
     type Output = T;
     type PhantomOutput = Used;
     fn combine(self) -> T {
@@ -61,7 +233,13 @@ impl<T, D: TupDefault> CanCombine<D, Used> for (T, T) {
     }
 }
 
-impl<T, D: TupDefault> CanCombine<Unused, D> for ((), T) {
+impl<F> CanCombineDisjoint<Unused, Unused, F> for ((), ()) {
+    type Output = ();
+    type PhantomOutput = Unused;
+    fn combine(self) {}
+}
+
+impl<T, D: TupDefault, F> CanCombineDisjoint<Unused, D, F> for ((), T) {
     type Output = T;
     type PhantomOutput = D;
     fn combine(self) -> T {
@@ -69,10 +247,183 @@ impl<T, D: TupDefault> CanCombine<Unused, D> for ((), T) {
     }
 }
 
-impl<T, D: TupDefault> CanCombine<D, Unused> for (T, ()) {
+impl<T, D: TupDefault, F> CanCombineDisjoint<D, Unused, F> for (T, ()) {
     type Output = T;
     type PhantomOutput = D;
     fn combine(self) -> T {
         self.0
     }
 }
+
+/// Combines two values of an argument that is contained in both tups when calling `merge_with`.
+///
+/// Arguments that are only contained in one of the tups are kept as is, and defaulted arguments
+/// behave just like they do with the [`Add`](core::ops::Add) trait.
+///
+/// ```rust
+/// # use named_tup::{tup, Combiner, Sum};
+/// let stock = tup!(eggs: 6, ingredients: vec!["milk"]);
+/// let delivery = tup!(eggs: 12, ingredients: vec!["flour", "sugar"], price: 3);
+///
+/// struct Restock;
+///
+/// impl Combiner<i32> for Restock {
+///     fn combine(&self, lhs: i32, rhs: i32) -> i32 {
+///         Sum.combine(lhs, rhs)
+///     }
+/// }
+///
+/// impl<T> Combiner<Vec<T>> for Restock {
+///     fn combine(&self, mut lhs: Vec<T>, rhs: Vec<T>) -> Vec<T> {
+///         lhs.extend(rhs);
+///         lhs
+///     }
+/// }
+///
+/// let stock = stock.merge_with(delivery, Restock);
+/// assert_eq!(stock, tup!(eggs: 18, ingredients: vec!["milk", "flour", "sugar"], price: 3));
+///
+/// assert_eq!(tup!(count: 2).merge_with(tup!(count: 3), Sum), tup!(count: 5));
+/// ```
+pub trait Combiner<T> {
+    /// Combines the left and right hand side values.
+    fn combine(&self, lhs: T, rhs: T) -> T;
+}
+
+/// A [`Combiner`] that adds both values together using the [`Add`](core::ops::Add) trait.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Sum;
+
+impl<T: Add<Output = T>> Combiner<T> for Sum {
+    fn combine(&self, lhs: T, rhs: T) -> T {
+        lhs + rhs
+    }
+}
+
+/// Defines how two Tup's arguments can be merged using a [`Combiner`], `F` being the marker type
+/// of the argument
+#[diagnostic::on_unimplemented(
+    message = "the tup argument `{F}` cannot be merged with `{C}`",
+    label = "the argument `{F}` is set in both tups with different types or `{C}` cannot combine it",
+    note = "an argument contained in both tups must have the same type in each, and the combiner must implement `Combiner` for it"
+)]
+pub trait CanCombineWith<C, P1, P2, F> {
+    type Output;
+    type PhantomOutput;
+    fn combine(self, combiner: &C) -> Self::Output;
+}
+
+/// The type of an argument after merging two tups with `merge_with`.
+pub type MergedWith<T1, T2, C, P1, P2, F> = <(T1, T2) as CanCombineWith<C, P1, P2, F>>::Output;
+
+/// Whether an argument is used, unused or defaulted after merging two tups with `merge_with`.
+pub type MergedWithPhantom<T1, T2, C, P1, P2, F> =
+    <(T1, T2) as CanCombineWith<C, P1, P2, F>>::PhantomOutput;
+
+impl<T, C: Combiner<T>, F> CanCombineWith<C, Used, Used, F> for (T, T) {
+    type Output = T;
+    type PhantomOutput = Used;
+    fn combine(self, combiner: &C) -> T {
+        combiner.combine(self.0, self.1)
+    }
+}
+
+impl<T, C, F> CanCombineWith<C, Used, Unused, F> for (T, ()) {
+    type Output = T;
+    type PhantomOutput = Used;
+    fn combine(self, _combiner: &C) -> T {
+        self.0
+    }
+}
+
+impl<T, C, F> CanCombineWith<C, Unused, Used, F> for ((), T) {
+    type Output = T;
+    type PhantomOutput = Used;
+    fn combine(self, _combiner: &C) -> T {
+        self.1
+    }
+}
+
+impl<C, F> CanCombineWith<C, Unused, Unused, F> for ((), ()) {
+    type Output = ();
+    type PhantomOutput = Unused;
+    fn combine(self, _combiner: &C) {}
+}
+
+impl<T, C, D: TupDefault, F> CanCombineWith<C, Used, D, F> for (T, T) {
+    type Output = T;
+    type PhantomOutput = Used;
+    fn combine(self, _combiner: &C) -> T {
+        self.0
+    }
+}
+
+impl<T, C, D1: TupDefault, D2: TupDefault, F> CanCombineWith<C, D1, D2, F> for (T, T) {
+    type Output = T;
+    type PhantomOutput = D2;
+    fn combine(self, _combiner: &C) -> T {
+        self.1
+    }
+}
+
+impl<T, C, D: TupDefault, F> CanCombineWith<C, D, Used, F> for (T, T) {
+    type Output = T;
+    type PhantomOutput = Used;
+    fn combine(self, _combiner: &C) -> T {
+        self.1
+    }
+}
+
+impl<T, C, D: TupDefault, F> CanCombineWith<C, Unused, D, F> for ((), T) {
+    type Output = T;
+    type PhantomOutput = D;
+    fn combine(self, _combiner: &C) -> T {
+        self.1
+    }
+}
+
+impl<T, C, D: TupDefault, F> CanCombineWith<C, D, Unused, F> for (T, ()) {
+    type Output = T;
+    type PhantomOutput = D;
+    fn combine(self, _combiner: &C) -> T {
+        self.0
+    }
+}
+
+/// Defines how a Tup's argument is affected when subtracting another Tup from it, `F` being the
+/// marker type of the argument
+#[diagnostic::on_unimplemented(
+    message = "the tup argument `{F}` cannot be removed",
+    label = "the argument `{F}` is neither set, defaulted nor unused in the subtracted tup"
+)]
+pub trait CanRemove<P1, P2, F> {
+    type Output;
+    type PhantomOutput;
+    fn remove(self) -> Self::Output;
+}
+
+/// The type of an argument after subtracting a tup.
+pub type Removed<T, P1, P2, F> = <T as CanRemove<P1, P2, F>>::Output;
+
+/// Whether an argument is used, unused or defaulted after subtracting a tup.
+pub type RemovedPhantom<T, P1, P2, F> = <T as CanRemove<P1, P2, F>>::PhantomOutput;
+
+impl<T, P, F> CanRemove<P, Used, F> for T {
+    type Output = ();
+    type PhantomOutput = Unused;
+    fn remove(self) {}
+}
+
+impl<T, P, D: TupDefault, F> CanRemove<P, D, F> for T {
+    type Output = ();
+    type PhantomOutput = Unused;
+    fn remove(self) {}
+}
+
+impl<T, P, F> CanRemove<P, Unused, F> for T {
+    type Output = T;
+    type PhantomOutput = P;
+    fn remove(self) -> T {
+        self
+    }
+}
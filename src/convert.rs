@@ -1,4 +1,7 @@
-use crate::tup_struct::{TupDefault, Unused, Used};
+use core::fmt::{Display, Formatter};
+use core::marker::PhantomData;
+
+use crate::tup_struct::{TupDefault, Unused, Unvalidated, Used, Validated};
 
 named_tup_derive::sealed_trait_builder!();
 
@@ -51,6 +54,21 @@ named_tup_derive::sealed_trait_builder!();
 /// }
 /// ```
 ///
+/// <br>
+///
+/// Converting fails with an error naming each argument that is required but not set, and each
+/// argument that is set but not contained in the target.
+///
+/// ```compile_fail
+/// # use named_tup::{TupInto, Tup, tup, tup_default};
+/// #[tup_default]
+/// fn open(options: Tup!(write: bool, read: bool = true)) {}
+///
+/// // error: the tup argument `write` is required but was not set
+/// // error: the tup argument `timeout` is not accepted by the target tup
+/// open(tup!(read: false, timeout: 5).into_tup());
+/// ```
+///
 pub trait TupInto<T>: private::Sealed {
     /// Performs the conversion.
     #[must_use]
@@ -72,6 +90,11 @@ pub trait TupInto<T>: private::Sealed {
 ///     assert_eq!(rick, tup!(funny: true, lyrics: "Never Gonna Give You Up..."))
 /// }
 /// ```
+#[diagnostic::on_unimplemented(
+    message = "a tup of type `{T}` cannot be converted into `{Self}`",
+    label = "the arguments of this tup do not match the target tup",
+    note = "an argument without a default must be set, and an argument that the target tup does not contain cannot be set"
+)]
 pub trait TupFrom<T>: private::Sealed {
     /// Performs the conversion
     #[must_use]
@@ -88,47 +111,399 @@ where
     }
 }
 
-/// A helper trait to figure out if a tup field can transformed.
-pub trait CanInto<OLD, NEW> {
+/// A fallible version of [`TupInto`] that also runs the validators of the target tup.
+///
+/// This trait is sealed as it should only ever be implemented on the Tup type.
+///
+/// Any argument can be given a validator with `where`, which has access to the value of the
+/// argument through its name. The conversion follows the same rules as [`TupInto`] and then fails
+/// with an [`InvalidArgument`] naming the first argument whose value is rejected, whether it was
+/// set or defaulted.
+///
+/// ```rust
+/// # use named_tup::{TryTupInto, tup, Tup, tup_default};
+/// #[tup_default]
+/// fn address(
+///     options: Tup!(host: &'static str where !host.is_empty(), port: i32 = 8080 where port > 0),
+/// ) -> String {
+///     format!("{}:{}", options.host, options.port)
+/// }
+///
+/// let valid = tup!(host: "localhost").try_into_tup().map(address);
+/// assert_eq!(valid.unwrap(), "localhost:8080");
+///
+/// let invalid = tup!(host: "localhost", port: -1).try_into_tup().map(address);
+/// assert_eq!(invalid.unwrap_err().argument(), "port");
+///
+/// let invalid = tup!(host: "").try_into_tup().map(address);
+/// assert_eq!(invalid.unwrap_err().argument(), "host");
+/// ```
+///
+/// A tup can only be converted into one with validated arguments with [`TryTupInto`], so the
+/// validators cannot be skipped with [`TupInto`] or [`TupProject`].
+///
+/// ```compile_fail
+/// # use named_tup::{TupInto, tup, Tup, tup_default};
+/// #[tup_default]
+/// fn serve(options: Tup!(count: u16 = 8080 where count > 0)) -> u16 {
+///     options.count
+/// }
+///
+/// // error: the tup argument `count` is validated by the target tup
+/// serve(tup!(count: 0).into_tup());
+/// ```
+///
+/// The validators only run during the conversion. The arguments of a tup are public fields, so a
+/// value assigned to one of them afterwards is not checked, and the tup has to be converted again
+/// with [`TryTupInto`] to validate it.
+///
+/// ```rust
+/// # use named_tup::{TryTupInto, tup, Tup, tup_default};
+/// #[tup_default]
+/// fn reset(mut options: Tup!(port: i32 = 8080 where port > 0)) -> i32 {
+///     // Not checked by the validator of `port`.
+///     options.port = -1;
+///     options.port
+/// }
+///
+/// assert_eq!(reset(tup!().try_into_tup().unwrap()), -1);
+/// ```
+pub trait TryTupInto<T>: private::Sealed {
+    /// Performs the conversion.
+    fn try_into_tup(self) -> Result<T, InvalidArgument>;
+}
+
+/// A fallible version of [`TupFrom`] that also runs the validators of the target tup.
+///
+/// This trait is sealed as it should only ever be implemented on the Tup type.
+///
+/// For more information please look at the [`TryTupInto`] trait.
+pub trait TryTupFrom<T>: private::Sealed + Sized {
+    /// Performs the conversion.
+    fn try_from_tup(_: T) -> Result<Self, InvalidArgument>;
+}
+
+impl<T, U> TryTupInto<U> for T
+where
+    U: TryTupFrom<T>,
+    T: private::Sealed,
+{
+    fn try_into_tup(self) -> Result<U, InvalidArgument> {
+        U::try_from_tup(self)
+    }
+}
+
+/// The error returned by [`TryTupInto`] when the value of an argument is rejected by its
+/// validator. It implements `std::error::Error` when the `std` feature is enabled.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct InvalidArgument {
+    argument: &'static str,
+}
+
+impl InvalidArgument {
+    #[doc(hidden)]
+    pub fn new(argument: &'static str) -> Self {
+        InvalidArgument { argument }
+    }
+
+    /// The name of the argument that was rejected.
+    pub fn argument(&self) -> &'static str {
+        self.argument
+    }
+}
+
+impl Display for InvalidArgument {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the tup argument `{}` is invalid", self.argument)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidArgument {}
+
+/// Runs the validator of every argument of a tup, see [`TryTupInto`].
+pub trait ValidateTup: Sized {
+    fn validate(self) -> Result<Self, InvalidArgument>;
+}
+
+/// A lossy version of [`TupInto`] that discards the arguments the target tup does not contain.
+///
+/// This trait is sealed as it should only ever be implemented on the Tup type.
+///
+/// Every other argument follows the same rules as [`TupInto`], so defaults are still applied and
+/// required arguments must still be set. This allows a broad options tup to be passed into
+/// narrower functions.
+///
+/// ```rust
+/// # use named_tup::{TupProject, tup, Tup, tup_default};
+/// let options = tup!(host: "localhost", port: 8080, timeout: 30);
+///
+/// assert_eq!(address(options.project()), "localhost:8080");
+/// assert_eq!(wait(options.project()), 30);
+///
+/// #[tup_default]
+/// fn address(options: Tup!(host: &'static str, port: i32 = 80)) -> String {
+///     format!("{}:{}", options.host, options.port)
+/// }
+///
+/// #[tup_default]
+/// fn wait(options: Tup!(timeout: i32, read: bool = true)) -> i32 {
+///     options.timeout
+/// }
+/// ```
+pub trait TupProject<T>: private::Sealed {
+    /// Performs the conversion, dropping any argument that `T` does not contain.
+    #[must_use]
+    fn project(self) -> T;
+}
+
+/// A helper trait to figure out if a tup field can transformed, `F` being the marker type of the
+/// field.
+#[diagnostic::on_unimplemented(
+    message = "the tup argument `{F}` cannot be converted from `{OLD}` to `{NEW}`",
+    label = "the argument `{F}` does not match the target tup",
+    note = "an argument without a default must be set, and an argument that the target tup does not contain cannot be set"
+)]
+pub trait CanInto<OLD, NEW, F> {
     type Output;
-    fn into(self) -> Self::Output;
+    /// The default computed with [`ResolveDefault`](crate::tup_struct::ResolveDefault) when the
+    /// argument is not set and the target defaults it, `()` otherwise.
+    type Default;
+    fn into(self, default: Self::Default) -> Self::Output;
 }
 
-impl CanInto<Unused, Unused> for () {
+/// The type of an argument after converting it from `OLD` to `NEW`.
+pub type Converted<T, OLD, NEW, F> = <T as CanInto<OLD, NEW, F>>::Output;
+
+impl<F> CanInto<Unused, Unused, F> for () {
     type Output = ();
-    fn into(self) {}
+    type Default = ();
+    fn into(self, _default: ()) {}
 }
 
-impl<T> CanInto<Used, Used> for T {
+impl<T, F> CanInto<Used, Used, F> for T {
     type Output = T;
-    fn into(self) -> T {
+    type Default = ();
+    fn into(self, _default: ()) -> T {
         self
     }
 }
 
-impl<D: TupDefault> CanInto<Unused, D> for () {
+impl<D: TupDefault, F> CanInto<Unused, D, F> for () {
     type Output = D::Output;
-    fn into(self) -> D::Output {
-        D::default()
+    type Default = D::Output;
+    fn into(self, default: D::Output) -> D::Output {
+        default
     }
 }
 
-impl<T, D> CanInto<Used, D> for T
+impl<T, D, F> CanInto<Used, D, F> for T
 where
     D: TupDefault<Output = T>,
 {
     type Output = T;
-    fn into(self) -> T {
+    type Default = ();
+    fn into(self, _default: ()) -> T {
         self
     }
 }
 
-impl<T, D> CanInto<D, Used> for T
+impl<T, D, F> CanInto<D, Used, F> for T
 where
     D: TupDefault<Output = T>,
 {
     type Output = T;
-    fn into(self) -> T {
+    type Default = ();
+    fn into(self, _default: ()) -> T {
         self
     }
 }
+
+/// Converts between two defaults of the same type, such as the identical defaults of tups written
+/// in different items, keeping the value that is set.
+impl<T, D1, D2, F> CanInto<D1, D2, F> for T
+where
+    D1: TupDefault<Output = T>,
+    D2: TupDefault<Output = T>,
+{
+    type Output = T;
+    type Default = ();
+    fn into(self, _default: ()) -> T {
+        self
+    }
+}
+
+/// Stands in for a required argument `F` that was not set when converting a tup.
+pub struct MissingField<F>(PhantomData<F>);
+
+/// Stands in for an argument `F` that was set but that the target tup does not contain.
+pub struct UnexpectedField<F>(PhantomData<F>);
+
+/// Stands in for an argument `F` that the target tup validates when converting without running
+/// its validator.
+pub struct UncheckedField<F>(PhantomData<F>);
+
+impl<F> CanInto<Unused, Used, F> for () {
+    type Output = MissingField<F>;
+    type Default = ();
+    fn into(self, _default: ()) -> MissingField<F> {
+        MissingField(PhantomData)
+    }
+}
+
+impl<T, F> CanInto<Used, Unused, F> for T {
+    type Output = ();
+    type Default = ();
+    fn into(self, _default: ()) {}
+}
+
+impl<T, D: TupDefault, F> CanInto<D, Unused, F> for T {
+    type Output = ();
+    type Default = ();
+    fn into(self, _default: ()) {}
+}
+
+impl<T: CanInto<OLD, P, F>, OLD, P: Unvalidated, V, F> CanInto<OLD, Validated<P, V>, F> for T {
+    type Output = T::Output;
+    type Default = T::Default;
+    fn into(self, default: T::Default) -> T::Output {
+        CanInto::<OLD, P, F>::into(self, default)
+    }
+}
+
+impl<T: CanInto<P, NEW, F>, P: Unvalidated, V, NEW: Unvalidated, F> CanInto<Validated<P, V>, NEW, F>
+    for T
+{
+    type Output = T::Output;
+    type Default = T::Default;
+    fn into(self, default: T::Default) -> T::Output {
+        CanInto::<P, NEW, F>::into(self, default)
+    }
+}
+
+/// Never implemented, so that converting a tup that is missing a required argument fails with an
+/// error naming the argument.
+#[diagnostic::on_unimplemented(
+    message = "the tup argument `{F}` is required but was not set",
+    label = "this tup does not set `{F}`",
+    note = "set `{F}` or give it a default in the target tup"
+)]
+pub trait Required<F> {}
+
+/// Never implemented, so that converting a tup into one that validates an argument without
+/// running its validator fails with an error naming the argument.
+#[diagnostic::on_unimplemented(
+    message = "the tup argument `{F}` is validated by the target tup",
+    label = "this conversion does not run the validator of `{F}`",
+    note = "convert with `try_into_tup` or `try_from_tup` instead"
+)]
+pub trait Unchecked<F> {}
+
+/// Never implemented, so that converting a tup that contains an argument the target does not
+/// accept fails with an error naming the argument.
+#[diagnostic::on_unimplemented(
+    message = "the tup argument `{F}` is not accepted by the target tup",
+    label = "this tup sets `{F}`",
+    note = "remove `{F}` from this tup or add it to the target tup"
+)]
+pub trait Accepted<F> {}
+
+/// The kind of a used argument.
+pub struct IsUsed;
+
+/// The kind of an unused argument.
+pub struct IsUnused;
+
+/// The kind of a defaulted argument.
+pub struct IsDefault;
+
+/// The kind of a validated argument of the target tup, `K` being the kind of its phantom type.
+pub struct IsValidated<K>(PhantomData<K>);
+
+/// Maps the phantom type of an argument to a kind that does not overlap with the others, so that
+/// [`CheckArgument`] can always select a single implementation and report its own error.
+pub trait ArgumentKind {
+    type Kind;
+    /// The kind of the argument when it is in the target tup, where its validator must be run.
+    type TargetKind;
+}
+
+impl ArgumentKind for Used {
+    type Kind = IsUsed;
+    type TargetKind = IsUsed;
+}
+
+impl ArgumentKind for Unused {
+    type Kind = IsUnused;
+    type TargetKind = IsUnused;
+}
+
+impl<D: TupDefault> ArgumentKind for D {
+    type Kind = IsDefault;
+    type TargetKind = IsDefault;
+}
+
+impl<P: ArgumentKind, V> ArgumentKind for Validated<P, V> {
+    type Kind = P::Kind;
+    type TargetKind = IsValidated<P::Kind>;
+}
+
+/// Marks a conversion that fails on arguments the target tup does not accept, see [`TupFrom`].
+pub struct Strict;
+
+/// Marks a conversion that discards arguments the target tup does not accept, see
+/// [`TupProject`].
+pub struct Lossy;
+
+/// Marks a conversion that fails on arguments the target tup does not accept and runs the
+/// validators of the target tup, see [`TryTupFrom`].
+pub struct Validating;
+
+/// Checks that the argument `F` can be converted between the kinds `(OLD, NEW)` by a conversion
+/// of mode `M`, naming the argument when it is either missing or unexpected.
+#[diagnostic::on_unimplemented(
+    message = "the tup argument `{F}` cannot be converted",
+    label = "the argument `{F}` does not match the target tup"
+)]
+pub trait CheckArgument<F, M> {}
+
+impl<F, M> CheckArgument<F, M> for (IsUnused, IsUnused) {}
+
+impl<F, M> CheckArgument<F, M> for (IsUsed, IsUsed) {}
+
+impl<F, M> CheckArgument<F, M> for (IsUnused, IsDefault) {}
+
+impl<F, M> CheckArgument<F, M> for (IsUsed, IsDefault) {}
+
+impl<F, M> CheckArgument<F, M> for (IsDefault, IsUsed) {}
+
+impl<F, M> CheckArgument<F, M> for (IsDefault, IsDefault) {}
+
+impl<F, M> CheckArgument<F, M> for (IsUnused, IsUsed) where MissingField<F>: Required<F> {}
+
+impl<F> CheckArgument<F, Strict> for (IsUsed, IsUnused) where UnexpectedField<F>: Accepted<F> {}
+
+impl<F> CheckArgument<F, Strict> for (IsDefault, IsUnused) where UnexpectedField<F>: Accepted<F> {}
+
+impl<F> CheckArgument<F, Lossy> for (IsUsed, IsUnused) {}
+
+impl<F> CheckArgument<F, Lossy> for (IsDefault, IsUnused) {}
+
+impl<F> CheckArgument<F, Validating> for (IsUsed, IsUnused) where UnexpectedField<F>: Accepted<F> {}
+
+impl<F> CheckArgument<F, Validating> for (IsDefault, IsUnused) where UnexpectedField<F>: Accepted<F> {}
+
+impl<F, K1, K2> CheckArgument<F, Validating> for (K1, IsValidated<K2>) where
+    (K1, K2): CheckArgument<F, Strict>
+{
+}
+
+impl<F, K1, K2> CheckArgument<F, Strict> for (K1, IsValidated<K2>) where
+    UncheckedField<F>: Unchecked<F>
+{
+}
+
+impl<F, K1, K2> CheckArgument<F, Lossy> for (K1, IsValidated<K2>) where
+    UncheckedField<F>: Unchecked<F>
+{
+}
@@ -0,0 +1,46 @@
+//! Marker types for each tup argument, used to access arguments generically with [`HasField`].
+
+named_tup_derive::field_markers_builder!();
+
+/// Gives access to an argument of a tup through its marker type from the [`fields`](crate::fields)
+/// module, so that functions can accept any tup containing a certain argument.
+///
+/// It is implemented by every tup that contains the argument, including defaulted ones.
+///
+/// ```rust
+/// # use named_tup::{tup, HasField};
+/// use named_tup::fields::timeout;
+///
+/// fn double_timeout<T: HasField<timeout, Output = u32>>(mut options: T) -> u32 {
+///     *options.get_mut() *= 2;
+///     options.take()
+/// }
+///
+/// assert_eq!(double_timeout(tup!(timeout: 5)), 10);
+/// assert_eq!(double_timeout(tup!(timeout: 3, read: true)), 6);
+///
+/// let options = tup!(timeout: 3_u32, read: true);
+/// assert_eq!(HasField::<timeout>::get(&options), &3);
+/// ```
+///
+/// A tup that does not contain the argument does not implement the trait.
+///
+/// ```compile_fail
+/// # use named_tup::{tup, HasField};
+/// # use named_tup::fields::timeout;
+/// fn get_timeout<T: HasField<timeout, Output = u32>>(options: T) -> u32 {
+///     options.take()
+/// }
+///
+/// get_timeout(tup!(read: true));
+/// ```
+pub trait HasField<F> {
+    /// The type of the argument.
+    type Output;
+    /// Returns a reference to the argument.
+    fn get(&self) -> &Self::Output;
+    /// Returns a mutable reference to the argument.
+    fn get_mut(&mut self) -> &mut Self::Output;
+    /// Consumes the tup, returning the argument.
+    fn take(self) -> Self::Output;
+}
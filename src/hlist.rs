@@ -0,0 +1,706 @@
+//! An alternative representation of tups, produced by the [`htup!`](crate::htup) and
+//! [`HTup!`](crate::HTup) macros when the `hlist` feature is enabled.
+//!
+//! Every tup made with [`tup!`](crate::tup) is the same struct with two generic parameters per
+//! argument used anywhere in the project, which makes error messages and compile times grow with
+//! the number of arguments. A tup made with [`htup!`](crate::htup) is instead a list of [`HCons`]
+//! cells, one for each argument it contains, sorted by argument name and ending with [`HNil`]. Its
+//! type therefore only mentions the arguments that it actually uses.
+//!
+//! Adding, converting with [`TupInto`](crate::TupInto), [`TupProject`] or
+//! [`TryTupInto`](crate::TryTupInto), comparing, hashing and debug printing work like they do for
+//! the other tups, and [`#[tup_default]`](crate::tup_default) handles defaults of [`HTup!`](crate::HTup)
+//! types. Arguments are accessed through the [`HasField`] trait. The two
+//! representations cannot be mixed, for example by adding them together.
+//!
+//! ```rust
+//! # use named_tup::{htup, tup_default, HasField, HTup, TupInto};
+//! use named_tup::fields::{count, price};
+//!
+//! #[tup_default]
+//! pub fn main() {
+//!     let cakes = htup!(count: 5) + htup!(price: 3);
+//!     assert_eq!(cakes, htup!(price: 3, count: 5));
+//!     assert_eq!(HasField::<price>::get(&cakes), &3);
+//!
+//!     let cakes: HTup!(count: i32, eggs: bool = true, price: i32) = cakes.into_tup();
+//!
+//!     // Will print tup { count: 5, eggs: true (=true), price: 3 }
+//!     println!("{cakes:?}");
+//! }
+//! # main()
+//! ```
+//!
+//! Arguments are read, changed and taken out of a tup through [`HasField`],
+//! and a tup is converted to and from one with defaults using [`TupInto`](crate::TupInto) and
+//! [`TupFrom`].
+//!
+//! ```rust
+//! # use named_tup::{htup, tup_default, HasField, HTup, TupFrom, TupInto};
+//! use named_tup::fields::{count, eggs, price};
+//!
+//! #[tup_default]
+//! pub fn main() {
+//!     let mut cakes = htup!(count: 5, eggs: true, price: 3);
+//!     *HasField::<count>::get_mut(&mut cakes) += 1;
+//!     assert_eq!(HasField::<count>::get(&cakes), &6);
+//!     assert_eq!(HasField::<eggs>::get(&cakes), &true);
+//!     assert_eq!(HasField::<price>::take(cakes), 3);
+//!
+//!     let order: HTup!(count: i32 = 1, price: i32 = 2) = htup!(price: 4).into_tup();
+//!     assert_eq!(order, htup!(count: 1, price: 4));
+//!     assert_eq!(*HasField::<count>::get(&order), 1);
+//!
+//!     let order: HTup!(count: i32, price: i32) = TupFrom::from_tup(order);
+//!     assert_eq!(order, htup!(count: 1, price: 4));
+//!     let empty: HTup!() = htup!().into_tup();
+//!     assert_eq!(empty, htup!());
+//! }
+//! ```
+//!
+//! As with the other tups, a tup cannot be converted into one that lacks some of its arguments.
+//!
+//! ```compile_fail
+//! # use named_tup::{htup, HTup, TupInto};
+//! let cakes: HTup!(count: i32) = htup!(count: 5, price: 3).into_tup();
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt::{Debug, DebugStruct, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Add;
+
+use crate::combine::{CanCombine, Combined, CombinedPhantom, RemoveValidator, WithoutValidator};
+use crate::convert::{
+    private, ArgumentKind, CanInto, CheckArgument, InvalidArgument, IsUnused, Lossy, Strict,
+    TryTupFrom, TupFrom, TupProject, ValidateTup, Validating,
+};
+use crate::fields::HasField;
+use crate::tup_struct::{
+    ArgumentOr, ConvertToDebugStruct, ResolveDefault, SourceArgument, TupDefaultFrom, Unused,
+    ValidateField,
+};
+
+/// The end of a tup.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct HNil;
+
+/// An argument of a tup followed by the rest of its arguments.
+///
+/// `F` is the marker type of the argument from the [`fields`](crate::fields) module, `T` its
+/// type and `P` whether it is used or defaulted.
+#[must_use]
+pub struct HCons<F, T, P, R> {
+    pub head: T,
+    pub tail: R,
+    _phantom: PhantomData<(F, P)>,
+}
+
+impl<F, T, P, R> HCons<F, T, P, R> {
+    pub fn new(head: T, tail: R) -> Self {
+        HCons {
+            head,
+            tail,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<F, T: Clone, P, R: Clone> Clone for HCons<F, T, P, R> {
+    fn clone(&self) -> Self {
+        HCons::new(self.head.clone(), self.tail.clone())
+    }
+}
+
+impl<F, T: Copy, P, R: Copy> Copy for HCons<F, T, P, R> {}
+
+/// Implemented by the marker types of the [`fields`](crate::fields) module.
+pub trait FieldMarker {
+    /// The position of the argument in the sorted list of arguments, as a binary number.
+    type Index;
+    /// The name of the argument.
+    const NAME: &'static str;
+}
+
+/// A zero bit of an index followed by its less significant bits.
+pub struct B0<B>(PhantomData<B>);
+
+/// A one bit of an index followed by its less significant bits.
+pub struct B1<B>(PhantomData<B>);
+
+/// The end of an index.
+pub struct BEnd;
+
+/// The first index is smaller than the second.
+pub struct Less;
+
+/// Both indices are the same.
+pub struct Equal;
+
+/// The first index is greater than the second.
+pub struct Greater;
+
+/// Compares two indices of the same length.
+pub trait Compare<Rhs> {
+    type Output;
+}
+
+impl Compare<BEnd> for BEnd {
+    type Output = Equal;
+}
+
+impl<A, B> Compare<B1<B>> for B0<A> {
+    type Output = Less;
+}
+
+impl<A, B> Compare<B0<B>> for B1<A> {
+    type Output = Greater;
+}
+
+impl<A: Compare<B>, B> Compare<B0<B>> for B0<A> {
+    type Output = A::Output;
+}
+
+impl<A: Compare<B>, B> Compare<B1<B>> for B1<A> {
+    type Output = A::Output;
+}
+
+/// The result of comparing the indices of two argument markers.
+type CompareFields<F1, F2> =
+    <<F1 as FieldMarker>::Index as Compare<<F2 as FieldMarker>::Index>>::Output;
+
+/// Merges two sorted tups, combining the arguments they both contain. This is what adding two
+/// tups does.
+///
+/// ```rust
+/// # use named_tup::htup;
+/// use named_tup::hlist::Merge;
+///
+/// assert_eq!(htup!(count: 5).merge(htup!(price: 3)), htup!(count: 5, price: 3));
+/// assert_eq!(htup!(price: 3) + htup!(count: 5), htup!(count: 5, price: 3));
+/// assert_eq!(htup!() + htup!(count: 5), htup!(count: 5));
+/// assert_eq!(htup!(count: 5) + htup!(), htup!(count: 5));
+/// assert_eq!(htup!() + htup!(), htup!());
+/// ```
+///
+/// Only tups can be added to a tup.
+///
+/// ```compile_fail
+/// # use named_tup::htup;
+/// let cakes = htup!() + 5;
+/// ```
+///
+/// ```compile_fail
+/// # use named_tup::htup;
+/// let cakes = htup!(count: 5) + 5;
+/// ```
+pub trait Merge<Rhs> {
+    type Output;
+    fn merge(self, rhs: Rhs) -> Self::Output;
+}
+
+impl Merge<HNil> for HNil {
+    type Output = HNil;
+    fn merge(self, _rhs: HNil) -> HNil {
+        self
+    }
+}
+
+impl<F, T, P, R> Merge<HCons<F, T, P, R>> for HNil {
+    type Output = HCons<F, T, P, R>;
+    fn merge(self, rhs: HCons<F, T, P, R>) -> Self::Output {
+        rhs
+    }
+}
+
+impl<F, T, P, R> Merge<HNil> for HCons<F, T, P, R> {
+    type Output = Self;
+    fn merge(self, _rhs: HNil) -> Self {
+        self
+    }
+}
+
+impl<F1, T1, P1, R1, F2, T2, P2, R2> Merge<HCons<F2, T2, P2, R2>> for HCons<F1, T1, P1, R1>
+where
+    F1: FieldMarker,
+    F2: FieldMarker,
+    F1::Index: Compare<F2::Index>,
+    Self: MergeBy<HCons<F2, T2, P2, R2>, CompareFields<F1, F2>>,
+{
+    type Output = <Self as MergeBy<HCons<F2, T2, P2, R2>, CompareFields<F1, F2>>>::Output;
+    fn merge(self, rhs: HCons<F2, T2, P2, R2>) -> Self::Output {
+        MergeBy::merge_by(self, rhs)
+    }
+}
+
+/// Merges two sorted tups depending on how their first arguments compare.
+pub trait MergeBy<Rhs, O> {
+    type Output;
+    fn merge_by(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<F1, T1, P1, R1: Merge<Rhs>, Rhs> MergeBy<Rhs, Less> for HCons<F1, T1, P1, R1> {
+    type Output = HCons<F1, T1, P1, R1::Output>;
+    fn merge_by(self, rhs: Rhs) -> Self::Output {
+        HCons::new(self.head, self.tail.merge(rhs))
+    }
+}
+
+impl<F1, T1, P1, R1, F2, T2, P2, R2> MergeBy<HCons<F2, T2, P2, R2>, Greater>
+    for HCons<F1, T1, P1, R1>
+where
+    Self: Merge<R2>,
+{
+    type Output = HCons<F2, T2, P2, <Self as Merge<R2>>::Output>;
+    fn merge_by(self, rhs: HCons<F2, T2, P2, R2>) -> Self::Output {
+        HCons::new(rhs.head, self.merge(rhs.tail))
+    }
+}
+
+impl<F, T1, P1, R1, T2, P2, R2> MergeBy<HCons<F, T2, P2, R2>, Equal> for HCons<F, T1, P1, R1>
+where
+    P1: RemoveValidator,
+    P2: RemoveValidator,
+    (T1, T2): CanCombine<WithoutValidator<P1>, WithoutValidator<P2>, F>,
+    R1: Merge<R2>,
+{
+    type Output = HCons<
+        F,
+        Combined<T1, T2, WithoutValidator<P1>, WithoutValidator<P2>, F>,
+        CombinedPhantom<T1, T2, WithoutValidator<P1>, WithoutValidator<P2>, F>,
+        R1::Output,
+    >;
+    fn merge_by(self, rhs: HCons<F, T2, P2, R2>) -> Self::Output {
+        HCons::new(
+            CanCombine::<WithoutValidator<P1>, WithoutValidator<P2>, F>::combine((
+                self.head, rhs.head,
+            )),
+            self.tail.merge(rhs.tail),
+        )
+    }
+}
+
+impl<Rhs> Add<Rhs> for HNil
+where
+    Self: Merge<Rhs>,
+{
+    type Output = <Self as Merge<Rhs>>::Output;
+    fn add(self, rhs: Rhs) -> Self::Output {
+        self.merge(rhs)
+    }
+}
+
+impl<F, T, P, R, Rhs> Add<Rhs> for HCons<F, T, P, R>
+where
+    Self: Merge<Rhs>,
+{
+    type Output = <Self as Merge<Rhs>>::Output;
+    fn add(self, rhs: Rhs) -> Self::Output {
+        self.merge(rhs)
+    }
+}
+
+/// Converts a tup into another one that contains the same arguments along with defaulted ones.
+/// Arguments the target does not contain are dropped when `M` is [`Lossy`] and rejected when it
+/// is [`Strict`] or [`Validating`], the only mode that can convert into validated arguments. The
+/// defaults of the target are first computed from `Root`, the whole tup being converted, and then
+/// moved into it along with the arguments.
+pub trait ConvertTo<Target, M, Root> {
+    type Defaults;
+    fn defaults(&self, root: &Root) -> Self::Defaults;
+    fn convert(self, defaults: Self::Defaults) -> Target;
+}
+
+impl<M, Root> ConvertTo<HNil, M, Root> for HNil {
+    type Defaults = ();
+    fn defaults(&self, _root: &Root) {}
+    fn convert(self, _defaults: ()) -> HNil {
+        self
+    }
+}
+
+impl<F, T, P, R, M, Root> ConvertTo<HCons<F, T, P, R>, M, Root> for HNil
+where
+    (): CanInto<Unused, P, F, Output = T>,
+    P: ArgumentKind
+        + ResolveDefault<Unused, Root, Default = <() as CanInto<Unused, P, F>>::Default>,
+    (IsUnused, P::TargetKind): CheckArgument<F, M>,
+    HNil: ConvertTo<R, M, Root>,
+{
+    type Defaults = (P::Default, <HNil as ConvertTo<R, M, Root>>::Defaults);
+    fn defaults(&self, root: &Root) -> Self::Defaults {
+        (P::resolve(root), self.defaults(root))
+    }
+    fn convert(self, defaults: Self::Defaults) -> HCons<F, T, P, R> {
+        HCons::new(
+            CanInto::<Unused, P, F>::into((), defaults.0),
+            self.convert(defaults.1),
+        )
+    }
+}
+
+impl<F, T, P, R, M, Root> ConvertTo<HNil, M, Root> for HCons<F, T, P, R>
+where
+    P: ArgumentKind,
+    (P::Kind, IsUnused): CheckArgument<F, M>,
+    R: ConvertTo<HNil, M, Root>,
+{
+    type Defaults = R::Defaults;
+    fn defaults(&self, root: &Root) -> Self::Defaults {
+        self.tail.defaults(root)
+    }
+    fn convert(self, defaults: Self::Defaults) -> HNil {
+        self.tail.convert(defaults)
+    }
+}
+
+impl<F1, T1, P1, R1, F2, T2, P2, R2, M, Root> ConvertTo<HCons<F2, T2, P2, R2>, M, Root>
+    for HCons<F1, T1, P1, R1>
+where
+    F1: FieldMarker,
+    F2: FieldMarker,
+    F1::Index: Compare<F2::Index>,
+    Self: ConvertBy<HCons<F2, T2, P2, R2>, CompareFields<F1, F2>, M, Root>,
+{
+    type Defaults =
+        <Self as ConvertBy<HCons<F2, T2, P2, R2>, CompareFields<F1, F2>, M, Root>>::Defaults;
+    fn defaults(&self, root: &Root) -> Self::Defaults {
+        <Self as ConvertBy<HCons<F2, T2, P2, R2>, CompareFields<F1, F2>, M, Root>>::defaults_by(
+            self, root,
+        )
+    }
+    fn convert(self, defaults: Self::Defaults) -> HCons<F2, T2, P2, R2> {
+        <Self as ConvertBy<HCons<F2, T2, P2, R2>, CompareFields<F1, F2>, M, Root>>::convert_by(
+            self, defaults,
+        )
+    }
+}
+
+/// Converts a tup depending on how its first argument compares to the first argument of the
+/// target. The argument is dropped or rejected when it is missing from the target.
+pub trait ConvertBy<Target, O, M, Root> {
+    type Defaults;
+    fn defaults_by(&self, root: &Root) -> Self::Defaults;
+    fn convert_by(self, defaults: Self::Defaults) -> Target;
+}
+
+impl<F, T1, P1, R1, T2, P2, R2, M, Root> ConvertBy<HCons<F, T2, P2, R2>, Equal, M, Root>
+    for HCons<F, T1, P1, R1>
+where
+    T1: CanInto<P1, P2, F, Output = T2>,
+    P1: ArgumentKind,
+    P2: ArgumentKind + ResolveDefault<P1, Root, Default = T1::Default>,
+    (P1::Kind, P2::TargetKind): CheckArgument<F, M>,
+    R1: ConvertTo<R2, M, Root>,
+{
+    type Defaults = (P2::Default, R1::Defaults);
+    fn defaults_by(&self, root: &Root) -> Self::Defaults {
+        (P2::resolve(root), self.tail.defaults(root))
+    }
+    fn convert_by(self, defaults: Self::Defaults) -> HCons<F, T2, P2, R2> {
+        HCons::new(
+            CanInto::<P1, P2, F>::into(self.head, defaults.0),
+            self.tail.convert(defaults.1),
+        )
+    }
+}
+
+impl<F1, T1, P1, R1, F2, T2, P2, R2, M, Root> ConvertBy<HCons<F2, T2, P2, R2>, Greater, M, Root>
+    for HCons<F1, T1, P1, R1>
+where
+    (): CanInto<Unused, P2, F2, Output = T2>,
+    P2: ArgumentKind
+        + ResolveDefault<Unused, Root, Default = <() as CanInto<Unused, P2, F2>>::Default>,
+    (IsUnused, P2::TargetKind): CheckArgument<F2, M>,
+    Self: ConvertTo<R2, M, Root>,
+{
+    type Defaults = (P2::Default, <Self as ConvertTo<R2, M, Root>>::Defaults);
+    fn defaults_by(&self, root: &Root) -> Self::Defaults {
+        (P2::resolve(root), self.defaults(root))
+    }
+    fn convert_by(self, defaults: Self::Defaults) -> HCons<F2, T2, P2, R2> {
+        HCons::new(
+            CanInto::<Unused, P2, F2>::into((), defaults.0),
+            self.convert(defaults.1),
+        )
+    }
+}
+
+impl<F1, T1, P1, R1, F2, T2, P2, R2, M, Root> ConvertBy<HCons<F2, T2, P2, R2>, Less, M, Root>
+    for HCons<F1, T1, P1, R1>
+where
+    P1: ArgumentKind,
+    (P1::Kind, IsUnused): CheckArgument<F1, M>,
+    R1: ConvertTo<HCons<F2, T2, P2, R2>, M, Root>,
+{
+    type Defaults = R1::Defaults;
+    fn defaults_by(&self, root: &Root) -> Self::Defaults {
+        self.tail.defaults(root)
+    }
+    fn convert_by(self, defaults: Self::Defaults) -> HCons<F2, T2, P2, R2> {
+        self.tail.convert(defaults)
+    }
+}
+
+/// Converts `source` after computing the defaults of the target from it.
+fn convert<Source, Target, M>(source: Source) -> Target
+where
+    Source: ConvertTo<Target, M, Source>,
+{
+    let defaults = source.defaults(&source);
+    source.convert(defaults)
+}
+
+impl private::Sealed for HNil {}
+
+impl<F, T, P, R> private::Sealed for HCons<F, T, P, R> {}
+
+impl<Source: ConvertTo<HNil, Strict, Source>> TupFrom<Source> for HNil {
+    fn from_tup(source: Source) -> Self {
+        convert::<_, _, Strict>(source)
+    }
+}
+
+impl<Source, F, T, P, R> TupFrom<Source> for HCons<F, T, P, R>
+where
+    Source: ConvertTo<HCons<F, T, P, R>, Strict, Source>,
+{
+    fn from_tup(source: Source) -> Self {
+        convert::<_, _, Strict>(source)
+    }
+}
+
+impl<Source: ConvertTo<HNil, Validating, Source>> TryTupFrom<Source> for HNil {
+    fn try_from_tup(source: Source) -> Result<Self, InvalidArgument> {
+        convert::<_, _, Validating>(source).validate()
+    }
+}
+
+impl<Source, F, T, P, R> TryTupFrom<Source> for HCons<F, T, P, R>
+where
+    Source: ConvertTo<HCons<F, T, P, R>, Validating, Source>,
+    Self: ValidateTup,
+{
+    fn try_from_tup(source: Source) -> Result<Self, InvalidArgument> {
+        convert::<_, _, Validating>(source).validate()
+    }
+}
+
+impl<Target> TupProject<Target> for HNil
+where
+    HNil: ConvertTo<Target, Lossy, HNil>,
+{
+    fn project(self) -> Target {
+        convert::<_, _, Lossy>(self)
+    }
+}
+
+impl<F, T, P, R, Target> TupProject<Target> for HCons<F, T, P, R>
+where
+    Self: ConvertTo<Target, Lossy, Self>,
+{
+    fn project(self) -> Target {
+        convert::<_, _, Lossy>(self)
+    }
+}
+
+impl ValidateTup for HNil {
+    fn validate(self) -> Result<Self, InvalidArgument> {
+        Ok(self)
+    }
+}
+
+impl<F, T, P, R> ValidateTup for HCons<F, T, P, R>
+where
+    F: FieldMarker,
+    P: ValidateField<T>,
+    R: ValidateTup,
+{
+    fn validate(self) -> Result<Self, InvalidArgument> {
+        let head = P::validate(self.head).map_err(|_| InvalidArgument::new(F::NAME))?;
+        Ok(HCons::new(head, self.tail.validate()?))
+    }
+}
+
+/// Gives access to the argument `F` of a tup depending on how it compares to the first argument.
+pub trait HasFieldBy<F, O> {
+    type Output;
+    fn get_by(&self) -> &Self::Output;
+    fn get_mut_by(&mut self) -> &mut Self::Output;
+    fn take_by(self) -> Self::Output;
+}
+
+impl<F, T, P, R> HasFieldBy<F, Equal> for HCons<F, T, P, R> {
+    type Output = T;
+    fn get_by(&self) -> &T {
+        &self.head
+    }
+    fn get_mut_by(&mut self) -> &mut T {
+        &mut self.head
+    }
+    fn take_by(self) -> T {
+        self.head
+    }
+}
+
+impl<F, F2, T, P, R: HasField<F>> HasFieldBy<F, Greater> for HCons<F2, T, P, R> {
+    type Output = R::Output;
+    fn get_by(&self) -> &R::Output {
+        self.tail.get()
+    }
+    fn get_mut_by(&mut self) -> &mut R::Output {
+        self.tail.get_mut()
+    }
+    fn take_by(self) -> R::Output {
+        self.tail.take()
+    }
+}
+
+impl<F, F2, T, P, R> HasField<F> for HCons<F2, T, P, R>
+where
+    F: FieldMarker,
+    F2: FieldMarker,
+    F::Index: Compare<F2::Index>,
+    Self: HasFieldBy<F, CompareFields<F, F2>>,
+{
+    type Output = <Self as HasFieldBy<F, CompareFields<F, F2>>>::Output;
+    fn get(&self) -> &Self::Output {
+        self.get_by()
+    }
+    fn get_mut(&mut self) -> &mut Self::Output {
+        self.get_mut_by()
+    }
+    fn take(self) -> Self::Output {
+        self.take_by()
+    }
+}
+
+impl<F, P: TupDefaultFrom<Root>, Root> ArgumentOr<F, P, P::Output, Root> for HNil {
+    fn argument_or(&self, root: &Root) -> P::Output {
+        P::default_from(root)
+    }
+}
+
+impl<F, F2, T2, P2, R, P, T, Root> ArgumentOr<F, P, T, Root> for HCons<F2, T2, P2, R>
+where
+    F: FieldMarker,
+    F2: FieldMarker,
+    F::Index: Compare<F2::Index>,
+    Self: ArgumentOrBy<F, P, T, Root, CompareFields<F, F2>>,
+{
+    fn argument_or(&self, root: &Root) -> T {
+        self.argument_or_by(root)
+    }
+}
+
+/// Gives the value of the argument `F` of a tup, or computes it with the default `P`, depending on
+/// how it compares to the first argument. See [`ArgumentOr`].
+pub trait ArgumentOrBy<F, P, T, Root, O> {
+    fn argument_or_by(&self, root: &Root) -> T;
+}
+
+impl<F, T2, P2, R, P, T, Root> ArgumentOrBy<F, P, T, Root, Equal> for HCons<F, T2, P2, R>
+where
+    P2: SourceArgument<T2, P, T, Root>,
+{
+    fn argument_or_by(&self, root: &Root) -> T {
+        P2::argument(&self.head, root)
+    }
+}
+
+impl<F, F2, T2, P2, R, P, T, Root> ArgumentOrBy<F, P, T, Root, Greater> for HCons<F2, T2, P2, R>
+where
+    R: ArgumentOr<F, P, T, Root>,
+{
+    fn argument_or_by(&self, root: &Root) -> T {
+        self.tail.argument_or(root)
+    }
+}
+
+impl<F, F2, T2, P2, R, P, Root> ArgumentOrBy<F, P, P::Output, Root, Less> for HCons<F2, T2, P2, R>
+where
+    P: TupDefaultFrom<Root>,
+{
+    fn argument_or_by(&self, root: &Root) -> P::Output {
+        P::default_from(root)
+    }
+}
+
+/// Adds the arguments of a tup to a debug struct, `Root` being the whole tup from which defaults
+/// are computed.
+pub trait DebugFields<Root> {
+    fn debug_fields(&self, debug_struct: &mut DebugStruct, root: &Root);
+}
+
+impl<Root> DebugFields<Root> for HNil {
+    fn debug_fields(&self, _debug_struct: &mut DebugStruct, _root: &Root) {}
+}
+
+impl<F, T, P, R, Root> DebugFields<Root> for HCons<F, T, P, R>
+where
+    F: FieldMarker,
+    T: Debug,
+    P: ConvertToDebugStruct<Root>,
+    R: DebugFields<Root>,
+{
+    fn debug_fields(&self, debug_struct: &mut DebugStruct, root: &Root) {
+        P::convert(debug_struct, F::NAME, &self.head, root);
+        self.tail.debug_fields(debug_struct, root);
+    }
+}
+
+impl Debug for HNil {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.debug_struct("tup").finish()
+    }
+}
+
+impl<F, T, P, R> Debug for HCons<F, T, P, R>
+where
+    Self: DebugFields<Self>,
+{
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        let mut debug_struct = f.debug_struct("tup");
+        self.debug_fields(&mut debug_struct, self);
+        debug_struct.finish()
+    }
+}
+
+impl<F, T: PartialEq, P1, P2, R1: PartialEq<R2>, R2> PartialEq<HCons<F, T, P2, R2>>
+    for HCons<F, T, P1, R1>
+{
+    fn eq(&self, other: &HCons<F, T, P2, R2>) -> bool {
+        self.head == other.head && self.tail == other.tail
+    }
+}
+
+impl<F, T: Eq, P, R: Eq> Eq for HCons<F, T, P, R> {}
+
+// Cannot implement true ord as that requires the same type.
+impl<F, T: Ord, P, R: Ord> Ord for HCons<F, T, P, R> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.head
+            .cmp(&other.head)
+            .then_with(|| self.tail.cmp(&other.tail))
+    }
+}
+
+#[allow(clippy::non_canonical_partial_ord_impl)]
+impl<F, T: PartialOrd, P1, P2, R1: PartialOrd<R2>, R2> PartialOrd<HCons<F, T, P2, R2>>
+    for HCons<F, T, P1, R1>
+{
+    fn partial_cmp(&self, other: &HCons<F, T, P2, R2>) -> Option<Ordering> {
+        match self.head.partial_cmp(&other.head) {
+            Some(Ordering::Equal) => self.tail.partial_cmp(&other.tail),
+            cmp => cmp,
+        }
+    }
+}
+
+impl<F, T: Hash, P, R: Hash> Hash for HCons<F, T, P, R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.head.hash(state);
+        self.tail.hash(state);
+    }
+}
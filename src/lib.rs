@@ -42,6 +42,48 @@
 //! arguments = ["count", "ingredients", "eggs", "price"]
 //! ```
 //!
+//! If your build system does not run the build scripts of your crates, the arguments can instead
+//! be listed in a `named-tup.toml` file. It is looked for in the target directory and in the
+//! directory of each crate registered with inwelling, then in their parents up to the root of
+//! their workspace, so it usually sits next to the `Cargo.toml` of your workspace. A target
+//! directory outside of the workspace is not searched, so set the `NAMED_TUP_CONFIG` environment
+//! variable to the path of the file instead, which is then the only config file used. The
+//! `sources` list gives directories, relative to the file, that are scanned for arguments. A
+//! `named-tup.toml` file that did not exist during the last build is only picked up once
+//! `named-tup-derive` is rebuilt, for example after `cargo clean -p named-tup-derive`.
+//!
+//! ```toml
+//! arguments = ["count", "ingredients", "eggs", "price"]
+//! sources = ["src", "tests"]
+//! ```
+//!
+//! Arguments can also be added through the `NAMED_TUP_ARGUMENTS` environment variable as a comma
+//! separated list, for example using the `[env]` section of your `.cargo/config.toml`.
+//!
+//! To find out where each argument was found, set the `NAMED_TUP_REPORT` environment variable
+//! when building. A summary is then printed as build warnings and a full report is written to
+//! `identifiers.json` in the output directory of `named-tup-derive`.
+//!
+//! By default the arguments of every crate are merged into a single tup type, so the tups of each
+//! crate carry the arguments of all the others. A crate can instead get its own tup type
+//! containing only the arguments it uses by setting `namespace = true` and calling
+//! [`tup_namespace!()`](tup_namespace) at its root. Its arguments are then left out of the shared
+//! tup type and it does not call `inwelling::register()`, the build script of named-tup instead
+//! finds it among the members of the workspace being built and their path dependencies. Its tups
+//! can still be converted with [`TupInto`] to and from the shared tups and the tups of the
+//! namespaced crates it depends on through a path.
+//!
+//! ```toml
+//! [package.metadata.inwelling.named-tup-derive]
+//! namespace = true
+//! ```
+//!
+//! Every tup is the same struct with two generic parameters per argument, which makes error
+//! messages and compile times grow with the number of arguments. Enabling the `hlist` feature adds
+//! the `htup!` and `HTup!` macros, which represent a tup as a sorted list of only the arguments it
+//! contains instead, see the `hlist` module. Their arguments are accessed through [`HasField`]
+//! rather than as fields.
+//!
 //! <br>
 //!
 //! # Examples
@@ -90,10 +132,18 @@
 //!     // Open the file
 //! }
 //! ```
+//!
+//! Converting with [`.into_tup()`](TupInto) fails if the tup sets an argument the target does not
+//! contain, while [`.project()`](TupProject) silently drops such arguments.
 // Tup types in rustdoc of other crates get linked to here.
 #![doc(html_root_url = "https://docs.rs/named_tup/0.3.1")]
 
-pub use convert::{TupFrom, TupInto};
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use combine::{Combiner, Sum};
+pub use convert::{InvalidArgument, TryTupFrom, TryTupInto, TupFrom, TupInto, TupProject};
+pub use fields::HasField;
 /// The whole point.
 ///
 /// Produces a named tuple, a struct that
@@ -130,6 +180,20 @@ pub use convert::{TupFrom, TupInto};
 /// assert_eq!(nest, tup!(kingfisher: true, eagle: false, toucan: true))
 /// ```
 ///
+/// Every argument must have been found in your source files or registered beforehand, any other
+/// argument is reported as an error.
+/// ```compile_fail
+/// # use named_tup::tup;
+/// // Not used anywhere else, so it is not a known argument.
+/// let nest = tup!(kingfisher: true, pelican: true);
+/// ```
+///
+/// An argument can also only be given once.
+/// ```compile_fail
+/// # use named_tup::tup;
+/// let nest = tup!(kingfisher: true, toucan: true, kingfisher: false);
+/// ```
+///
 /// <br>
 ///
 /// # Tup as a type
@@ -171,6 +235,145 @@ pub use convert::{TupFrom, TupInto};
 /// }
 /// ```
 ///
+/// Any argument can also be given a validator with `where`, which is checked when converting with
+/// [`TryTupInto`], e.g. `Tup!(port: u16 where port > 0)` or `Tup!(port: u16 = 8080 where port > 0)`.
+/// A tup with validated arguments can only be converted into with [`TryTupInto`].
+///
+/// A default can refer to other arguments of the same tup, whether they have a default or not.
+/// When converting, it is computed from the values of the tup being converted, using the defaults
+/// of the arguments it does not set. These values are cloned, so a default can only depend on
+/// arguments whose type implements [`Clone`].
+///
+/// ```rust
+/// # use named_tup::{tup, Tup, tup_default, TupInto};
+/// #[tup_default]
+/// fn area(rect: Tup!(width: i32 = 1, height: i32 = width)) -> i32 {
+///     rect.width * rect.height
+/// }
+///
+/// assert_eq!(area(tup!().into_tup()), 1);
+/// assert_eq!(area(tup!(width: 3).into_tup()), 9);
+/// assert_eq!(area(tup!(width: 3, height: 2).into_tup()), 6);
+///
+/// #[tup_default]
+/// fn total_wait(request: Tup!(retries: u32, timeout: u32 = retries * 5)) -> u32 {
+///     request.retries * request.timeout
+/// }
+///
+/// assert_eq!(total_wait(tup!(retries: 2).into_tup()), 20);
+/// assert_eq!(total_wait(tup!(retries: 2, timeout: 1).into_tup()), 2);
+/// ```
+///
+/// Only the variables of a default that are not bound inside of it refer to other arguments, so a
+/// function called by the same name or a variable bound by `let`, a closure, a `match` arm or a
+/// loop does not. Arguments are also found in the arguments of macros such as `format!`,
+/// including the ones named inside of the format string.
+///
+/// ```rust
+/// # use named_tup::{tup, Tup, tup_default, TupInto};
+/// const fn width() -> i32 {
+///     4
+/// }
+///
+/// // Constant defaults cannot depend on other arguments.
+/// #[tup_default]
+/// fn area(rect: Tup!(width: i32, height: i32 = const { let depth = width() - 1; let width = 2; width * depth })) -> i32 {
+///     rect.width * rect.height
+/// }
+///
+/// assert_eq!(area(tup!(width: 2).into_tup()), 12);
+///
+/// // The required `width` does not implement `Clone`, so nothing can depend on it.
+/// #[derive(PartialEq, Debug)]
+/// struct Width(i32);
+///
+/// #[tup_default]
+/// fn size(
+///     rect: Tup!(
+///         width: Width,
+///         height: i32 = (|width: i32| width + 1)(1) + match 3 { width => width },
+///         opacity: i32 = [1, 2].into_iter().map(|width| width * 10).sum(),
+///         count: i32 = { let mut sum = 0; for width in 0..3 { sum += width; } sum },
+///     ),
+/// ) -> (i32, i32, i32) {
+///     (rect.height, rect.opacity, rect.count)
+/// }
+///
+/// assert_eq!(size(tup!(width: Width(1)).into_tup()), (5, 30, 3));
+///
+/// #[tup_default]
+/// fn describe(rect: Tup!(width: i32 = 2, height: i32 = 3, name: String = format!("{width}x{}", height))) -> String {
+///     rect.name
+/// }
+///
+/// assert_eq!(describe(tup!().into_tup()), "2x3");
+/// assert_eq!(describe(tup!(width: 5).into_tup()), "5x3");
+/// ```
+///
+/// Arguments cannot be used in macros whose arguments are not expressions, as they could not be
+/// found there.
+///
+/// ```compile_fail
+/// # use named_tup::{Tup, tup_default};
+/// macro_rules! double {
+///     ($value:ident => twice) => {
+///         $value * 2
+///     };
+/// }
+///
+/// #[tup_default]
+/// fn area(rect: Tup!(width: i32, height: i32 = double!(width => twice))) -> i32 {
+///     rect.width * rect.height
+/// }
+/// ```
+///
+/// A default is evaluated every time it is needed. Writing `= const expr` requires the expression
+/// to be evaluated at compile time, while `= #[lazy] expr` evaluates it once per process and then
+/// clones it, which needs the `std` feature and is a compile error without it.
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use named_tup::{tup, Tup, tup_default, TupInto};
+/// # use std::collections::HashMap;
+/// #[tup_default]
+/// fn lookup(
+///     options: Tup!(names: HashMap<i32, &'static str> = #[lazy] HashMap::from([(1, "one")]), count: i32 = const 1 + 1),
+/// ) -> Option<&'static str> {
+///     options.names.get(&options.count).copied()
+/// }
+///
+/// assert_eq!(lookup(tup!(count: 1).into_tup()), Some("one"));
+/// assert_eq!(lookup(tup!().into_tup()), None);
+///
+/// #[tup_default]
+/// fn first(options: Tup!(values: [i32; 2] = #[lazy] [1, 2], pair: (i32, i32) = #[lazy] (3, 4))) -> i32 {
+///     options.values[0] + options.pair.0
+/// }
+///
+/// assert_eq!(first(tup!().into_tup()), 4);
+/// # }
+/// ```
+///
+/// Without the attribute, `lazy` is an ordinary name, so a function called `lazy` can be used
+/// in a default like any other.
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use named_tup::{tup, Tup, tup_default, TupInto};
+/// fn lazy(x: i32) -> i32 {
+///     x * 2
+/// }
+///
+/// #[tup_default]
+/// fn area(rect: Tup!(width: i32 = lazy(1), height: i32 = #[lazy] lazy(3))) -> i32 {
+///     rect.width * rect.height
+/// }
+///
+/// assert_eq!(area(tup!().into_tup()), 12);
+/// assert_eq!(area(tup!(width: 1).into_tup()), 6);
+/// # }
+/// ```
+///
 /// <br>
 ///
 /// # Tup type
@@ -198,6 +401,48 @@ pub use convert::{TupFrom, TupInto};
 /// println!("{:?}", tup!( married: true, farmer: "Joe"));
 /// ```
 ///
+/// If all of its arguments implement [`Display`](core::fmt::Display) then so does the tup, which
+/// is meant for user-facing messages. It prints each argument with its
+/// [`Display`](core::fmt::Display) representation, without the defaults that the
+/// [`Debug`](core::fmt::Debug) implementation of the tup shows. Formatting options given to the
+/// tup are not applied to its arguments.
+/// ```rust
+/// # use named_tup::tup;
+/// use core::fmt::{Display, Formatter};
+///
+/// // Implements Display but not Debug.
+/// struct Money(u32);
+///
+/// impl Display for Money {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "${}", self.0)
+///     }
+/// }
+///
+/// let person = tup!(name: "Joe", confidence: 0.4567);
+/// assert_eq!(format!("{person}"), "(confidence: 0.4567, name: Joe)");
+/// assert_eq!(format!("{person:.2}"), "(confidence: 0.4567, name: Joe)");
+/// assert_eq!(format!("{}", tup!(count: Money(3))), "(count: $3)");
+/// assert_eq!(format!("{}", tup!()), "()");
+/// ```
+///
+/// The arguments contained in a tup can also be inspected at runtime. `LEN` and `FIELD_NAMES`
+/// give the number and sorted names of the arguments, while `for_each_field` visits each
+/// argument as a [`Debug`](core::fmt::Debug) value. Only the arguments the tup contains need to
+/// implement [`Debug`](core::fmt::Debug), and they can borrow from their surroundings.
+/// ```rust
+/// # use named_tup::{tup, Tup};
+/// assert_eq!(<Tup!(cows: u8, bulls: u8)>::LEN, 2);
+///
+/// let farmer = String::from("Joe");
+/// let farm = tup!(cows: 4, farmer: &farmer);
+/// assert_eq!(farm.field_names(), ["cows", "farmer"]);
+///
+/// let mut fields = vec![];
+/// farm.for_each_field(|name, value| fields.push(format!("{name} = {value:?}")));
+/// assert_eq!(fields, ["cows = 4", "farmer = \"Joe\""]);
+/// ```
+///
 /// Finally the [`Add`](core::ops::Add) trait is implemented so that you can transform between
 /// different tup types. If both sides contain a certain argument, precedence is given to the
 /// right hand side.
@@ -209,6 +454,87 @@ pub use convert::{TupFrom, TupInto};
 ///
 /// assert_eq!(combined_farm, tup!(roosters: 4, hens: 56, dragons: 7, dogs: 3));
 /// ```
+///
+/// To give precedence to the left hand side instead use `merge_left`, or use `merge_disjoint`
+/// to make sure that the two tups never contain the same argument.
+/// ```rust
+/// # use named_tup::tup;
+/// let farm1 = tup!(roosters: 4, dragons: 7, dogs: 1);
+/// let farm2 = tup!(hens: 56, dogs: 3);
+///
+/// assert_eq!(farm1.merge_left(farm2), tup!(roosters: 4, hens: 56, dragons: 7, dogs: 1));
+/// assert_eq!(tup!(cows: 2).merge_disjoint(tup!(bulls: 1)), tup!(cows: 2, bulls: 1));
+/// ```
+///
+/// ```compile_fail
+/// # use named_tup::tup;
+/// // Both sides contain dogs.
+/// tup!(roosters: 4, dogs: 1).merge_disjoint(tup!(hens: 56, dogs: 3));
+/// ```
+///
+/// Arguments contained in both tups can also be combined together by calling `merge_with`
+/// with a [`Combiner`].
+/// ```rust
+/// # use named_tup::{tup, Sum};
+/// let farm1 = tup!(roosters: 4, dragons: 7, dogs: 1);
+/// let farm2 = tup!(hens: 56, dogs: 3);
+///
+/// assert_eq!(farm1.merge_with(farm2, Sum), tup!(roosters: 4, hens: 56, dragons: 7, dogs: 4));
+/// ```
+///
+/// The [`Sub`](core::ops::Sub) trait removes every argument contained in the right hand side,
+/// whatever its value. This is useful to strip out the arguments that have already been used
+/// before passing the rest along.
+/// ```rust
+/// # use named_tup::tup;
+/// let farm = tup!(roosters: 4, hens: 56, dragons: 7, dogs: 3);
+/// let safe_farm = farm - tup!(dragons: ());
+///
+/// assert_eq!(safe_farm, tup!(roosters: 4, hens: 56, dogs: 3));
+/// assert_eq!(safe_farm - tup!(cows: ()), tup!(roosters: 4, hens: 56, dogs: 3));
+/// ```
+///
+/// A tup can also be built up one argument at a time with the `with_` and `without_` methods
+/// generated for every argument, which is handy in fluent APIs.
+/// ```rust
+/// # use named_tup::tup;
+/// let farm = tup!().with_cows(3).with_bulls(1);
+///
+/// assert_eq!(farm, tup!(cows: 3, bulls: 1));
+/// assert_eq!(farm.with_cows(4).without_bulls(), tup!(cows: 4));
+/// ```
+///
+/// With the `serde` feature enabled the Tup type, as well as the ones generated by
+/// [`tup_namespace!()`](tup_namespace), also implements `Serialize` and `Deserialize` as a map of
+/// its arguments. Deserializing into a defaulted Tup fills in any missing argument
+/// with its default value, computed from the deserialized arguments it depends on, and rejects
+/// the values of validated arguments that their validator rejects.
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// # use named_tup::{tup, Tup, tup_default};
+/// #[tup_default]
+/// pub fn main() {
+///     let json = serde_json::to_string(&tup!(host: "localhost", port: 8080)).unwrap();
+///     assert_eq!(json, r#"{"host":"localhost","port":8080}"#);
+///
+///     let config: Tup!(host: String, port: u16 = 80) =
+///         serde_json::from_str(r#"{"host":"example.com"}"#).unwrap();
+///     assert_eq!(config, tup!(host: "example.com".to_string(), port: 80));
+///
+///     let size: Tup!(width: i32 = 1, height: i32 = width) =
+///         serde_json::from_str(r#"{"width":3}"#).unwrap();
+///     assert_eq!(size, tup!(width: 3, height: 3));
+///
+///     let request: Tup!(retries: u32, timeout: u32 = retries * 5) =
+///         serde_json::from_str(r#"{"retries":2}"#).unwrap();
+///     assert_eq!(request, tup!(retries: 2, timeout: 10));
+///
+///     let invalid = serde_json::from_str::<Tup!(port: u16 where port > 0)>(r#"{"port":0}"#);
+///     assert!(invalid.is_err());
+/// }
+/// # main()
+/// # }
+/// ```
 pub use named_tup_derive::tup;
 /// An attribute macro that allows you to derive defaults.
 ///
@@ -233,7 +559,256 @@ pub use named_tup_derive::tup;
 ///     n_tup.into_tup()
 /// }
 /// ```
+///
+/// Each default is turned into a struct whose name is a hash of the argument's name, type,
+/// default expression and evaluation, so that builds are reproducible. The structs of an item are
+/// put in a hidden module of their own next to it, so every default is evaluated in the scope of
+/// its item, even when another item has the same default. Identical defaults of the same item
+/// are the same type, while tups from different items can still be compared with each other and
+/// converted with [`.into_tup()`](TupInto).
+///
+/// ```rust
+/// # use named_tup::{tup, tup_default, Tup, TupInto};
+/// #[tup_default]
+/// fn keep(n_tup: Tup!(foo: i32 = 2)) -> Tup!(foo: i32 = 2) {
+///     n_tup
+/// }
+///
+/// #[tup_default]
+/// fn make(foo: i32) -> Tup!(foo: i32 = 2) {
+///     tup!(foo).into_tup()
+/// }
+///
+/// assert_eq!(keep(tup!().into_tup()), make(2));
+/// assert!(keep(tup!().into_tup()) < make(3));
+/// assert_eq!(keep(make(3).into_tup()), make(3));
+/// assert_eq!(vec![keep(tup!().into_tup()), make(3).into_tup()], [make(2), make(3)]);
+///
+/// mod small {
+///     use named_tup::{tup_default, Tup};
+///
+///     const COUNT: i32 = 1;
+///
+///     #[tup_default]
+///     pub fn count(order: Tup!(count: i32 = COUNT)) -> i32 {
+///         order.count
+///     }
+/// }
+///
+/// mod large {
+///     use named_tup::{tup_default, Tup};
+///
+///     const COUNT: i32 = 2;
+///
+///     #[tup_default]
+///     pub fn count(order: Tup!(count: i32 = COUNT)) -> i32 {
+///         order.count
+///     }
+/// }
+///
+/// assert_eq!(small::count(tup!().into_tup()), 1);
+/// assert_eq!(large::count(tup!().into_tup()), 2);
+/// ```
+///
+/// The attribute can also be put on an `impl` block or a trait, but not on their items. The
+/// structs of the defaults are as visible as the items using them, so defaults can be used in
+/// public signatures. Since every item gets its own structs, a trait method and its
+/// implementations need to share a type declared with [`tup_type!`].
+///
+/// ```rust
+/// # use named_tup::{tup, tup_default, tup_type, Tup, TupInto};
+/// pub struct Bakery;
+///
+/// tup_type!(pub Order = Tup!(count: i32 = 1));
+///
+/// #[tup_default]
+/// impl Bakery {
+///     pub fn bake(&self, eggs: Tup!(eggs: bool = true)) -> bool {
+///         let fallback = |order: Tup!(eggs: bool = false)| order.eggs;
+///         eggs.eggs != fallback(tup!().into_tup())
+///     }
+/// }
+///
+/// #[tup_default]
+/// pub trait Shop {
+///     type Receipt;
+///
+///     fn sell(&self, order: Order) -> i32;
+/// }
+///
+/// #[tup_default]
+/// impl Shop for Bakery {
+///     type Receipt = Tup!(price: i32 = 3);
+///
+///     fn sell(&self, order: Order) -> i32 {
+///         order.count
+///     }
+/// }
+///
+/// assert!(Bakery.bake(tup!().into_tup()));
+/// assert_eq!(Bakery.sell(tup!().into_tup()), 1);
+/// let receipt: <Bakery as Shop>::Receipt = tup!().into_tup();
+/// assert_eq!(receipt, tup!(price: 3));
+/// ```
+///
+/// The provided methods of a trait can take defaulted tups, which its implementations then
+/// inherit.
+///
+/// ```rust
+/// # use named_tup::{tup, tup_default, Tup, TupInto};
+/// #[tup_default]
+/// trait Canvas {
+///     fn fill(&self, colour: Tup!(opacity: f32 = 1.0)) -> f32 {
+///         colour.opacity
+///     }
+/// }
+///
+/// struct Screen;
+///
+/// impl Canvas for Screen {}
+///
+/// assert_eq!(Screen.fill(tup!().into_tup()), 1.0);
+/// assert_eq!(Screen.fill(tup!(opacity: 0.5).into_tup()), 0.5);
+/// ```
+///
+/// The associated types and constants of an implementation can use defaults as well.
+///
+/// ```rust
+/// # use named_tup::{tup, tup_default, Tup, TupInto};
+/// trait Shape {
+///     type Size;
+///     const ORIGIN: fn() -> Self::Size;
+/// }
+///
+/// struct Square;
+///
+/// #[tup_default]
+/// impl Shape for Square {
+///     type Size = Tup!(width: u32 = 1, height: u32 = 1);
+///     const ORIGIN: fn() -> Tup!(width: u32 = 1, height: u32 = 1) = || tup!().into_tup();
+/// }
+///
+/// let size: <Square as Shape>::Size = tup!(width: 2).into_tup();
+/// assert_eq!(size, tup!(width: 2, height: 1));
+/// assert_eq!((Square::ORIGIN)(), tup!(width: 1, height: 1));
+/// ```
+///
+/// So can type aliases,
+///
+/// ```rust
+/// # use named_tup::{tup, tup_default, Tup, TupInto};
+/// #[tup_default]
+/// type Point = Tup!(x: i32 = 0, y: i32 = 0);
+///
+/// let point: Point = tup!(y: 3).into_tup();
+/// assert_eq!(point, tup!(x: 0, y: 3));
+/// ```
+///
+/// along with constants and statics.
+///
+/// ```rust
+/// # use named_tup::{tup, tup_default, Tup, TupInto};
+/// # use std::sync::LazyLock;
+/// #[tup_default]
+/// const ORIGIN: fn() -> Tup!(x: i32 = 0, y: i32 = 0) = || tup!().into_tup();
+///
+/// #[tup_default]
+/// static PRICE: LazyLock<Tup!(price: i32 = 3)> = LazyLock::new(|| tup!().into_tup());
+///
+/// assert_eq!(ORIGIN(), tup!(x: 0, y: 0));
+/// assert_eq!(PRICE.price, 3);
+/// ```
+///
+/// A module cannot be annotated, its items have to be annotated instead.
+///
+/// ```compile_fail
+/// # use named_tup::tup_default;
+/// #[tup_default]
+/// mod shapes {
+///     use named_tup::Tup;
+///
+///     pub fn area(size: Tup!(width: u32 = 1, height: u32 = 1)) -> u32 {
+///         size.width * size.height
+///     }
+/// }
+/// ```
+///
+/// Libraries can therefore take defaulted tups in their public functions and have other crates
+/// call them with [`.into_tup()`](TupInto). The structs can also be given a wider visibility
+/// than their items with `#[tup_default(pub)]` or `#[tup_default(pub(crate))]`.
+///
+/// ```rust
+/// mod bakery {
+///     use named_tup::{tup_default, Tup};
+///
+///     #[tup_default(pub)]
+///     pub(crate) fn bake(order: Tup!(count: i32 = 1)) -> i32 {
+///         order.count
+///     }
+/// }
+///
+/// # use named_tup::{tup, TupInto};
+/// assert_eq!(bakery::bake(tup!().into_tup()), 1);
+/// ```
+///
+/// Anything other than a visibility is rejected.
+///
+/// ```compile_fail
+/// # use named_tup::{tup_default, Tup};
+/// // error: Expected a visibility such as `pub` or `pub(crate)`
+/// #[tup_default(public)]
+/// pub fn bake(order: Tup!(count: i32 = 1)) -> i32 {
+///     order.count
+/// }
+/// ```
 pub use named_tup_derive::tup_default;
+/// Declares named tup types that can be reused from other modules and crates.
+///
+/// Each declaration expands to a type alias with the given visibility, along with the structs of
+/// its defaults, so the defaults only have to be written once. No [`#[tup_default]`](tup_default)
+/// is needed wherever the alias is used.
+///
+/// ```rust
+/// # use named_tup::{tup, TupInto};
+/// mod options {
+///     use named_tup::{tup_type, Tup};
+///
+///     tup_type!(
+///         /// The options used to open a file.
+///         pub OpenOptions = Tup!(read: bool = true, write: bool = false);
+///         pub Timeout = Tup!(timeout: i32 = 5);
+///     );
+/// }
+///
+/// fn open_file(options: options::OpenOptions) -> (bool, bool) {
+///     (options.read, options.write)
+/// }
+///
+/// assert_eq!(open_file(tup!(write: true).into_tup()), (true, true));
+/// let timeout: options::Timeout = tup!().into_tup();
+/// assert_eq!(timeout, tup!(timeout: 5));
+/// ```
+pub use named_tup_derive::tup_type;
+
+/// Produces a tup represented as a list of only the arguments it contains, see the [`hlist`]
+/// module. It is used like [`tup!`].
+#[cfg(feature = "hlist")]
+pub use named_tup_derive::htup;
+/// Generates the tup type of a crate that sets `namespace = true` in its
+/// `[package.metadata.inwelling.named-tup-derive]`. It must be invoked at the root of the crate's
+/// library, or of its binary if it has none.
+///
+/// The type only contains the arguments found in the crate's own source files, or the ones given
+/// in its `arguments` list. The crate must be a member of the workspace being built or a path
+/// dependency of one, as that is where the build script of named-tup looks for it. The [`tup!`]
+/// and [`Tup!`] macros of the crate, including the ones in its tests, examples and doc tests, use
+/// it instead of the shared tup type of named-tup. The `examples/namespace` crate of the
+/// repository shows how it is used.
+pub use named_tup_derive::tup_namespace;
+/// Produces the type of a tup represented as a list of only the arguments it contains, see the
+/// [`hlist`] module. It is used like [`Tup!`].
+#[cfg(feature = "hlist")]
+pub use named_tup_derive::HTup;
 /// Produces a type annotation for the tup struct. If an expression is needed
 /// instead please use the [`tup!`] macro.
 ///
@@ -282,10 +857,65 @@ pub use named_tup_derive::Tup;
 
 mod combine;
 mod convert;
+pub mod fields;
+#[cfg(feature = "hlist")]
+pub mod hlist;
+#[cfg(feature = "serde")]
+mod serialize;
 mod tup_struct;
 
 //Not part of public api.
 #[doc(hidden)]
 pub mod __private {
-    pub use super::tup_struct::{Tup, TupDefault, Unused, Used};
+    pub use super::fields;
+    pub use super::tup_struct::Tup;
+    pub use super::tup_struct::{
+        ArgumentOr, TupDefault, TupDefaultFrom, TupValidator, Unused, Used, Validated,
+    };
+
+    // Caches the value of lazy defaults.
+    #[cfg(feature = "std")]
+    pub use std::sync::OnceLock;
+
+    // Named in the errors of tup conversions.
+    pub use super::convert::{
+        Accepted, MissingField, Required, Unchecked, UncheckedField, UnexpectedField,
+    };
+
+    // Used by the Tup structs generated in downstream crates by tup_namespace!().
+    pub mod tup_struct {
+        pub use crate::tup_struct::{
+            ArgumentOr, ConvertToDebugStruct, DisplayField, FieldPresence, Present, ResolveDefault,
+            SourceArgument, TupDefault, TupDefaultFrom, TupValidator, Unused, Used, ValidateField,
+            Validated, VisitField,
+        };
+    }
+
+    pub mod combine {
+        pub use crate::combine::{
+            CanCombine, CanCombineDisjoint, CanCombineLeft, CanCombineWith, CanRemove, Combined,
+            CombinedPhantom, MergedDisjoint, MergedDisjointPhantom, MergedLeft, MergedLeftPhantom,
+            MergedWith, MergedWithPhantom, RemoveValidator, Removed, RemovedPhantom,
+            WithoutValidator,
+        };
+    }
+
+    #[cfg(feature = "serde")]
+    pub use serde;
+
+    #[cfg(feature = "serde")]
+    pub mod serialize {
+        pub use crate::serialize::{DeserializeField, FieldIndex, FillDefault, SerializeField};
+    }
+
+    pub mod convert {
+        pub use crate::convert::{
+            ArgumentKind, CanInto, CheckArgument, Converted, InvalidArgument, Lossy, Strict,
+            TryTupFrom, TupFrom, TupProject, ValidateTup, Validating,
+        };
+
+        pub mod private {
+            pub use crate::convert::private::Sealed;
+        }
+    }
 }
@@ -0,0 +1,218 @@
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+
+use serde::de::{DeserializeSeed, Deserializer, Error, IgnoredAny, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize};
+
+use crate::convert::InvalidArgument;
+use crate::tup_struct::{
+    FieldPresence, SourceArgument, TupDefault, TupDefaultFrom, TupValidator, Unused, Used,
+    Validated,
+};
+
+/// A helper trait to serialise a Tup's argument depending on the phantom type.
+pub trait SerializeField<T>: FieldPresence {
+    fn serialize<M: SerializeMap>(
+        map: &mut M,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), M::Error>;
+}
+
+impl<T: Serialize> SerializeField<T> for Used {
+    fn serialize<M: SerializeMap>(
+        map: &mut M,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), M::Error> {
+        map.serialize_entry(name, value)
+    }
+}
+
+impl SerializeField<()> for Unused {
+    fn serialize<M: SerializeMap>(
+        _map: &mut M,
+        _name: &'static str,
+        _value: &(),
+    ) -> Result<(), M::Error> {
+        Ok(())
+    }
+}
+
+impl<T: Serialize, D: TupDefault<Output = T>> SerializeField<T> for D {
+    fn serialize<M: SerializeMap>(
+        map: &mut M,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), M::Error> {
+        map.serialize_entry(name, value)
+    }
+}
+
+impl<T, P: SerializeField<T>, V> SerializeField<T> for Validated<P, V> {
+    fn serialize<M: SerializeMap>(
+        map: &mut M,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), M::Error> {
+        P::serialize(map, name, value)
+    }
+}
+
+/// A helper trait to deserialise a Tup's argument depending on the phantom type.
+///
+/// Once the whole map has been read, the arguments are held in a Tup of [`Slot`](Self::Slot)s
+/// with [`SlotPhantom`](Self::SlotPhantom)s, from which the missing defaults are computed with
+/// [`FillDefault`] before producing the final values.
+pub trait DeserializeField<'de, T> {
+    /// How the argument is held while the missing defaults are computed.
+    type Slot;
+    /// The phantom type of the argument while the missing defaults are computed.
+    type SlotPhantom;
+    /// Reads the value of the argument from the map.
+    fn deserialize<M: MapAccess<'de>>(map: &mut M) -> Result<Option<T>, M::Error>;
+    /// Holds the value read from the map, failing if a required argument is missing.
+    fn slot<E: Error>(value: Option<T>, name: &'static str) -> Result<Self::Slot, E>;
+    /// Produces the final value from the slot and the default computed for it.
+    fn finish<E: Error>(slot: Self::Slot, default: Option<T>, name: &'static str) -> Result<T, E>;
+}
+
+impl<'de, T: Deserialize<'de>> DeserializeField<'de, T> for Used {
+    type Slot = T;
+    type SlotPhantom = Used;
+
+    fn deserialize<M: MapAccess<'de>>(map: &mut M) -> Result<Option<T>, M::Error> {
+        map.next_value().map(Some)
+    }
+
+    fn slot<E: Error>(value: Option<T>, name: &'static str) -> Result<T, E> {
+        value.ok_or_else(|| E::missing_field(name))
+    }
+
+    fn finish<E: Error>(slot: T, _default: Option<T>, _name: &'static str) -> Result<T, E> {
+        Ok(slot)
+    }
+}
+
+impl<'de> DeserializeField<'de, ()> for Unused {
+    type Slot = ();
+    type SlotPhantom = Unused;
+
+    fn deserialize<M: MapAccess<'de>>(map: &mut M) -> Result<Option<()>, M::Error> {
+        map.next_value::<IgnoredAny>()?;
+        Ok(None)
+    }
+
+    fn slot<E: Error>(_value: Option<()>, _name: &'static str) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn finish<E: Error>(_slot: (), _default: Option<()>, _name: &'static str) -> Result<(), E> {
+        Ok(())
+    }
+}
+
+impl<'de, T: Deserialize<'de>, D: TupDefault<Output = T>> DeserializeField<'de, T> for D {
+    type Slot = Option<T>;
+    type SlotPhantom = Partial<D>;
+
+    fn deserialize<M: MapAccess<'de>>(map: &mut M) -> Result<Option<T>, M::Error> {
+        map.next_value().map(Some)
+    }
+
+    fn slot<E: Error>(value: Option<T>, _name: &'static str) -> Result<Option<T>, E> {
+        Ok(value)
+    }
+
+    fn finish<E: Error>(slot: Option<T>, default: Option<T>, name: &'static str) -> Result<T, E> {
+        slot.or(default).ok_or_else(|| E::missing_field(name))
+    }
+}
+
+/// A validated argument is rejected when its validator fails, just like with
+/// [`TryTupInto`](crate::TryTupInto).
+impl<'de, T, P: DeserializeField<'de, T>, V: TupValidator<Value = T>> DeserializeField<'de, T>
+    for Validated<P, V>
+{
+    type Slot = P::Slot;
+    type SlotPhantom = P::SlotPhantom;
+
+    fn deserialize<M: MapAccess<'de>>(map: &mut M) -> Result<Option<T>, M::Error> {
+        P::deserialize(map)
+    }
+
+    fn slot<E: Error>(value: Option<T>, name: &'static str) -> Result<P::Slot, E> {
+        P::slot(value, name)
+    }
+
+    fn finish<E: Error>(slot: P::Slot, default: Option<T>, name: &'static str) -> Result<T, E> {
+        V::validate(P::finish(slot, default, name)?)
+            .map_err(|_| E::custom(InvalidArgument::new(name)))
+    }
+}
+
+/// The phantom type of an argument with the default `D` while deserialising, whose value may not
+/// have been read from the map.
+pub struct Partial<D>(PhantomData<D>);
+
+impl<T: Clone, P, D: TupDefaultFrom<Root, Output = T>, Root> SourceArgument<Option<T>, P, T, Root>
+    for Partial<D>
+{
+    fn argument(value: &Option<T>, root: &Root) -> T {
+        match value {
+            Some(value) => value.clone(),
+            None => D::default_from(root),
+        }
+    }
+}
+
+/// Computes the default of an argument that was missing from the map, from `Root`, the Tup of
+/// slots holding the deserialised arguments.
+pub trait FillDefault<T, Slot, Root> {
+    fn fill(slot: &Slot, root: &Root) -> Option<T>;
+}
+
+impl<T, Root> FillDefault<T, T, Root> for Used {
+    fn fill(_slot: &T, _root: &Root) -> Option<T> {
+        None
+    }
+}
+
+impl<Root> FillDefault<(), (), Root> for Unused {
+    fn fill(_slot: &(), _root: &Root) -> Option<()> {
+        None
+    }
+}
+
+impl<T, D: TupDefaultFrom<Root, Output = T>, Root> FillDefault<T, Option<T>, Root> for Partial<D> {
+    fn fill(slot: &Option<T>, root: &Root) -> Option<T> {
+        match slot {
+            Some(_) => None,
+            None => Some(D::default_from(root)),
+        }
+    }
+}
+
+/// Finds the position of a map key in the list of known identifiers.
+pub struct FieldIndex(pub &'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for FieldIndex {
+    type Value = Option<usize>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldIndex {
+    type Value = Option<usize>;
+
+    fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_str("a tup argument name")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(self.0.iter().position(|field| *field == v))
+    }
+}
@@ -1,4 +1,5 @@
-use core::fmt::{Debug, DebugStruct, Formatter};
+use core::fmt::{Debug, DebugStruct, Display, Formatter};
+use core::marker::PhantomData;
 
 named_tup_derive::tup_struct_builder!();
 
@@ -10,19 +11,219 @@ pub struct Used;
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Unused;
 
-/// A trait that is implemented by a unit struct that generates a default value
+/// A trait that is implemented by a unit struct that generates a default value, see
+/// [`TupDefaultFrom`].
 pub trait TupDefault {
     type Output;
-    fn default() -> Self::Output;
 }
 
-/// A trait that allows to convert a Tup to a Debug version depending on the phantom type.
-pub trait ConvertToDebugStruct {
-    fn convert(_: Self, debug_struct: &mut DebugStruct, name: &str, value: &dyn Debug);
+/// A trait that is implemented by a unit struct that checks the value of an argument
+pub trait TupValidator {
+    type Value;
+
+    /// Checks a value of the argument, returning it back as an error if it is invalid.
+    fn validate(value: Self::Value) -> Result<Self::Value, Self::Value>;
+}
+
+/// A phantom type indicating that an argument, set or defaulted depending on `P`, is checked by
+/// the [`TupValidator`] `V` when converting with [`TryTupInto`](crate::TryTupInto), the only
+/// conversion that can produce it. It otherwise behaves just like `P`.
+pub struct Validated<P, V>(PhantomData<(P, V)>);
+
+impl<P, V> Clone for Validated<P, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P, V> Copy for Validated<P, V> {}
+
+impl<P, V> Default for Validated<P, V> {
+    fn default() -> Self {
+        Validated(PhantomData)
+    }
+}
+
+/// A trait implemented by every phantom type but [`Validated`], so that the implementations
+/// forwarding a validated argument to its phantom type do not overlap.
+pub trait Unvalidated {}
+
+impl Unvalidated for Used {}
+
+impl Unvalidated for Unused {}
+
+impl<D: TupDefault> Unvalidated for D {}
+
+/// A trait that computes a default from `S`, the tup being converted. A default that depends on
+/// other arguments takes their values from it with [`ArgumentOr`], so it implements this trait
+/// for every `S` containing them, and the other defaults implement it for every `S`.
+pub trait TupDefaultFrom<S>: TupDefault {
+    fn default_from(source: &S) -> Self::Output;
+}
+
+/// A trait that computes the default needed to convert an argument from `OLD` to the phantom type
+/// implementing it, from the source tup `S`. Only an argument that the source does not set and
+/// the target defaults needs one, the default is `()` otherwise.
+pub trait ResolveDefault<OLD, S> {
+    type Default;
+    fn resolve(source: &S) -> Self::Default;
+}
+
+impl<OLD, S> ResolveDefault<OLD, S> for Used {
+    type Default = ();
+    fn resolve(_source: &S) {}
+}
+
+impl<OLD, S> ResolveDefault<OLD, S> for Unused {
+    type Default = ();
+    fn resolve(_source: &S) {}
+}
+
+impl<S, D: TupDefault> ResolveDefault<Used, S> for D {
+    type Default = ();
+    fn resolve(_source: &S) {}
+}
+
+impl<S, OLD: TupDefault, D: TupDefault> ResolveDefault<OLD, S> for D {
+    type Default = ();
+    fn resolve(_source: &S) {}
+}
+
+impl<S, D: TupDefaultFrom<S>> ResolveDefault<Unused, S> for D {
+    type Default = D::Output;
+    fn resolve(source: &S) -> D::Output {
+        D::default_from(source)
+    }
+}
+
+impl<S, D: TupDefault, P: Unvalidated, V> ResolveDefault<Validated<P, V>, S> for D
+where
+    D: ResolveDefault<P, S>,
+{
+    type Default = <D as ResolveDefault<P, S>>::Default;
+    fn resolve(source: &S) -> Self::Default {
+        <D as ResolveDefault<P, S>>::resolve(source)
+    }
 }
 
-impl ConvertToDebugStruct for Used {
-    fn convert(_: Self, debug_struct: &mut DebugStruct, name: &str, value: &dyn Debug) {
+impl<OLD, S, P, V> ResolveDefault<OLD, S> for Validated<P, V>
+where
+    P: ResolveDefault<OLD, S> + Unvalidated,
+{
+    type Default = P::Default;
+    fn resolve(source: &S) -> P::Default {
+        P::resolve(source)
+    }
+}
+
+/// A trait that gives the value of the argument `F` of a tup, which `Root` contains, or computes
+/// it with the default `P` from `Root` when the tup does not set it. `P` is [`Used`] for an
+/// argument without a default, which the tup then has to set.
+///
+/// The value is cloned out of the tup, so defaults can only depend on arguments whose type
+/// implements [`Clone`].
+pub trait ArgumentOr<F, P, T, Root> {
+    fn argument_or(&self, root: &Root) -> T;
+}
+
+/// A trait that gives the value of an argument for [`ArgumentOr`] depending on its phantom type
+/// in the tup, `P` being the default computing it otherwise.
+pub trait SourceArgument<T, P, Out, Root> {
+    fn argument(value: &T, root: &Root) -> Out;
+}
+
+impl<T: Clone, P, Root> SourceArgument<T, P, T, Root> for Used {
+    fn argument(value: &T, _root: &Root) -> T {
+        value.clone()
+    }
+}
+
+impl<T: Clone, P, Root, D: TupDefault<Output = T>> SourceArgument<T, P, T, Root> for D {
+    fn argument(value: &T, _root: &Root) -> T {
+        value.clone()
+    }
+}
+
+impl<D: TupDefaultFrom<Root>, Root> SourceArgument<(), D, D::Output, Root> for Unused {
+    fn argument(_value: &(), root: &Root) -> D::Output {
+        D::default_from(root)
+    }
+}
+
+impl<T, P, Out, Root, Q: SourceArgument<T, P, Out, Root>, V> SourceArgument<T, P, Out, Root>
+    for Validated<Q, V>
+{
+    fn argument(value: &T, root: &Root) -> Out {
+        Q::argument(value, root)
+    }
+}
+
+/// A trait that tells whether a Tup contains an argument depending on the phantom type.
+pub trait FieldPresence {
+    const PRESENT: bool;
+}
+
+impl FieldPresence for Used {
+    const PRESENT: bool = true;
+}
+
+impl FieldPresence for Unused {
+    const PRESENT: bool = false;
+}
+
+impl<T: TupDefault> FieldPresence for T {
+    const PRESENT: bool = true;
+}
+
+impl<P: FieldPresence, V> FieldPresence for Validated<P, V> {
+    const PRESENT: bool = P::PRESENT;
+}
+
+/// A trait implemented by the phantom types of arguments that a Tup contains.
+pub trait Present {}
+
+impl Present for Used {}
+
+impl<T: TupDefault> Present for T {}
+
+impl<P: Present, V> Present for Validated<P, V> {}
+
+/// A trait that hands a Tup's argument to the visitor of `for_each_field` depending on the
+/// phantom type, so that only the arguments the Tup contains need to implement [`Debug`].
+pub trait VisitField<T> {
+    fn visit(name: &'static str, value: &T, visitor: &mut dyn FnMut(&'static str, &dyn Debug));
+}
+
+impl<T: Debug> VisitField<T> for Used {
+    fn visit(name: &'static str, value: &T, visitor: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        visitor(name, value);
+    }
+}
+
+impl<T: Debug, D: TupDefault<Output = T>> VisitField<T> for D {
+    fn visit(name: &'static str, value: &T, visitor: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        visitor(name, value);
+    }
+}
+
+impl<T> VisitField<T> for Unused {
+    fn visit(_name: &'static str, _value: &T, _visitor: &mut dyn FnMut(&'static str, &dyn Debug)) {}
+}
+
+impl<T, P: VisitField<T>, V> VisitField<T> for Validated<P, V> {
+    fn visit(name: &'static str, value: &T, visitor: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        P::visit(name, value, visitor);
+    }
+}
+
+/// A trait that allows to convert a Tup to a Debug version depending on the phantom type. `S` is
+/// the Tup, from which a default is computed to be shown next to the value.
+pub trait ConvertToDebugStruct<S> {
+    fn convert(debug_struct: &mut DebugStruct, name: &str, value: &dyn Debug, source: &S);
+}
+
+impl<S> ConvertToDebugStruct<S> for Used {
+    fn convert(debug_struct: &mut DebugStruct, name: &str, value: &dyn Debug, _source: &S) {
         debug_struct.field(name, value);
     }
 }
@@ -39,15 +240,82 @@ impl<'a> Debug for DebugHijacker<'a> {
     }
 }
 
-impl<T: TupDefault> ConvertToDebugStruct for T
+impl<S, T: TupDefaultFrom<S>> ConvertToDebugStruct<S> for T
 where
     T::Output: Debug,
 {
-    fn convert(_: Self, debug_struct: &mut DebugStruct, name: &str, value: &dyn Debug) {
-        debug_struct.field(name, &DebugHijacker(value, &T::default()));
+    fn convert(debug_struct: &mut DebugStruct, name: &str, value: &dyn Debug, source: &S) {
+        debug_struct.field(name, &DebugHijacker(value, &T::default_from(source)));
     }
 }
 
-impl ConvertToDebugStruct for Unused {
-    fn convert(_: Self, _debug_struct: &mut DebugStruct, _name: &str, _value: &dyn Debug) {}
+impl<S> ConvertToDebugStruct<S> for Unused {
+    fn convert(_debug_struct: &mut DebugStruct, _name: &str, _value: &dyn Debug, _source: &S) {}
+}
+
+impl<S, P: ConvertToDebugStruct<S>, V> ConvertToDebugStruct<S> for Validated<P, V> {
+    fn convert(debug_struct: &mut DebugStruct, name: &str, value: &dyn Debug, source: &S) {
+        P::convert(debug_struct, name, value, source);
+    }
+}
+
+/// A trait that validates a Tup's argument depending on the phantom type.
+pub trait ValidateField<T> {
+    fn validate(value: T) -> Result<T, T>;
+}
+
+impl<T> ValidateField<T> for Used {
+    fn validate(value: T) -> Result<T, T> {
+        Ok(value)
+    }
+}
+
+impl<T> ValidateField<T> for Unused {
+    fn validate(value: T) -> Result<T, T> {
+        Ok(value)
+    }
+}
+
+impl<T, D: TupDefault<Output = T>> ValidateField<T> for D {
+    fn validate(value: T) -> Result<T, T> {
+        Ok(value)
+    }
+}
+
+impl<T, P, V: TupValidator<Value = T>> ValidateField<T> for Validated<P, V> {
+    fn validate(value: T) -> Result<T, T> {
+        V::validate(value)
+    }
+}
+
+/// A trait that allows to display a Tup's argument depending on the phantom type.
+///
+/// Arguments are written with their [`Display`] representation, without forwarding the
+/// formatting options given to the Tup.
+pub trait DisplayField<T>: FieldPresence {
+    fn fmt(value: &T, f: &mut Formatter<'_>) -> core::fmt::Result;
+}
+
+impl<T: Display> DisplayField<T> for Used {
+    fn fmt(value: &T, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{value}")
+    }
+}
+
+impl<T: Display, D: TupDefault<Output = T>> DisplayField<T> for D {
+    fn fmt(value: &T, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{value}")
+    }
+}
+
+impl DisplayField<()> for Unused {
+    fn fmt(_value: &(), _f: &mut Formatter<'_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T, P: DisplayField<T>, V> DisplayField<T> for Validated<P, V> {
+    fn fmt(value: &T, f: &mut Formatter<'_>) -> core::fmt::Result {
+        P::fmt(value, f)
+    }
 }
@@ -0,0 +1,58 @@
+use named_tup::{tup, tup_default, Tup, TupFrom, TupInto, TupProject};
+
+#[tup_default]
+fn open(options: Tup!(name: &'static str, read: bool = true, write: bool = false)) -> String {
+    format!("{} {} {}", options.name, options.read, options.write)
+}
+
+#[test]
+fn required_and_defaulted_arguments() {
+    assert_eq!(open(tup!(name: "a").into_tup()), "a true false");
+    assert_eq!(open(tup!(name: "a", write: true).into_tup()), "a true true");
+    assert_eq!(
+        open(tup!(write: true, read: false, name: "a").into_tup()),
+        "a false true"
+    );
+}
+
+#[test]
+#[tup_default]
+fn between_defaulted_tups() {
+    let defaulted: Tup!(width: i32 = 1, height: i32 = 2) = tup!(height: 5).into_tup();
+    let other: Tup!(width: i32 = 3, height: i32) = defaulted.into_tup();
+    let filled: Tup!(width: i32, height: i32) = other.into_tup();
+    assert_eq!(filled, tup!(width: 1, height: 5));
+
+    let filled: Tup!(width: i32, height: i32) = TupFrom::from_tup(tup!(width: 4, height: 5));
+    assert_eq!(filled, tup!(width: 4, height: 5));
+
+    let empty: Tup!() = tup!().into_tup();
+    assert_eq!(empty, tup!());
+}
+
+#[test]
+fn project_drops_unknown_arguments() {
+    let options = tup!(name: "a", write: true, cows: 3, bulls: 2);
+    assert_eq!(open(options.project()), "a true true");
+
+    let narrowed: Tup!(cows: i32) = options.project();
+    assert_eq!(narrowed, tup!(cows: 3));
+    let nothing: Tup!() = options.project();
+    assert_eq!(nothing, tup!());
+
+    // Projecting into a tup containing every argument is the same as converting into it.
+    let same: Tup!(name: &'static str, write: bool, cows: i32, bulls: i32) = options.project();
+    assert_eq!(same, options);
+}
+
+#[test]
+#[tup_default]
+fn project_applies_defaults() {
+    let defaulted: Tup!(width: i32 = 1, height: i32 = width * 2, cows: i32) =
+        tup!(cows: 3, bulls: 2).project();
+    let filled: Tup!(width: i32, height: i32, cows: i32) = defaulted.into_tup();
+    assert_eq!(filled, tup!(width: 1, height: 2, cows: 3));
+
+    let filled: Tup!(width: i32, height: i32) = defaulted.project();
+    assert_eq!(filled, tup!(width: 1, height: 2));
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "hlist")]
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use named_tup::fields::{count, eggs, price};
+use named_tup::{htup, tup_default, HTup, HasField, TryTupInto, TupInto, TupProject};
+
+fn hash(value: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn arguments_are_sorted() {
+    assert_eq!(htup!(price: 3, count: 5), htup!(count: 5, price: 3));
+    assert_eq!(
+        hash(htup!(price: 3, count: 5)),
+        hash(htup!(count: 5, price: 3))
+    );
+    assert!(htup!(count: 1, price: 9) < htup!(count: 2, price: 0));
+    assert_eq!(
+        format!("{:?}", htup!(price: 3, count: 5)),
+        "tup { count: 5, price: 3 }"
+    );
+    assert_eq!(format!("{:?}", htup!()), format!("{:?}", named_tup::tup!()));
+}
+
+#[test]
+#[tup_default]
+fn round_trip() {
+    let cakes = htup!(count: 5, price: 3);
+
+    let defaulted: HTup!(count: i32, eggs: bool = true, price: i32) = cakes.into_tup();
+    assert_eq!(
+        format!("{defaulted:?}"),
+        "tup { count: 5, eggs: true (=true), price: 3 }"
+    );
+
+    let filled: HTup!(count: i32, eggs: bool, price: i32) = defaulted.into_tup();
+    assert_eq!(filled, htup!(count: 5, eggs: true, price: 3));
+
+    let back: HTup!(count: i32, eggs: bool = true, price: i32) = filled.into_tup();
+    let back: HTup!(count: i32, eggs: bool, price: i32) = back.into_tup();
+    assert_eq!(back, filled);
+
+    let projected: HTup!(count: i32, price: i32) = filled.project();
+    assert_eq!(projected, cakes);
+}
+
+#[test]
+fn add_and_access() {
+    let mut cakes = htup!(count: 5) + htup!(price: 3, count: 6);
+    assert_eq!(cakes, htup!(count: 6, price: 3));
+    assert_eq!(htup!() + htup!(), htup!());
+
+    *HasField::<count>::get_mut(&mut cakes) += 1;
+    assert_eq!(HasField::<count>::get(&cakes), &7);
+    assert_eq!(HasField::<price>::take(cakes), 3);
+
+    let cakes = htup!(eggs: false) + cakes;
+    assert!(!HasField::<eggs>::get(&cakes));
+}
+
+#[test]
+fn invalid_arguments() {
+    let size = htup!(width: 1).try_into_tup().map(fill);
+    assert_eq!(size.unwrap(), htup!(width: 1, height: 2));
+
+    let size = htup!(width: 0).try_into_tup().map(fill);
+    assert_eq!(size.unwrap_err().argument(), "width");
+}
+
+// The marker types imported above would shadow the arguments of a validator.
+#[tup_default]
+fn fill(
+    size: HTup!(width: i32 where width > 0, height: i32 = 2),
+) -> HTup!(width: i32, height: i32) {
+    size.into_tup()
+}
@@ -0,0 +1,93 @@
+use named_tup::{tup, tup_default, Combiner, Sum, TryTupInto, Tup, TupInto};
+
+/// Keeps the longest of both values.
+struct Longest;
+
+impl Combiner<&'static str> for Longest {
+    fn combine(&self, lhs: &'static str, rhs: &'static str) -> &'static str {
+        match rhs.len() > lhs.len() {
+            true => rhs,
+            false => lhs,
+        }
+    }
+}
+
+#[test]
+fn add_prefers_right() {
+    let merged = tup!(cows: 1, bulls: 2) + tup!(bulls: 3, hens: 4);
+    assert_eq!(merged, tup!(cows: 1, bulls: 3, hens: 4));
+
+    assert_eq!(tup!() + tup!(), tup!());
+    assert_eq!(tup!(cows: 1) + tup!(), tup!(cows: 1));
+    assert_eq!(tup!() + tup!(cows: 1), tup!(cows: 1));
+}
+
+#[test]
+fn merge_left_prefers_left() {
+    let merged = tup!(cows: 1, bulls: 2).merge_left(tup!(bulls: 3, hens: 4));
+    assert_eq!(merged, tup!(cows: 1, bulls: 2, hens: 4));
+    assert_eq!(tup!().merge_left(tup!(cows: 1)), tup!(cows: 1));
+}
+
+#[test]
+fn merge_disjoint_keeps_both() {
+    let merged = tup!(cows: 1).merge_disjoint(tup!(bulls: 2, hens: 3));
+    assert_eq!(merged, tup!(cows: 1, bulls: 2, hens: 3));
+    assert_eq!(tup!().merge_disjoint(tup!()), tup!());
+}
+
+#[test]
+fn merge_with_combines_shared_arguments() {
+    let merged = tup!(cows: 1, bulls: 2).merge_with(tup!(bulls: 3, hens: 4), Sum);
+    assert_eq!(merged, tup!(cows: 1, bulls: 5, hens: 4));
+
+    let merged = tup!(name: "Joe", farmer: "Ann").merge_with(tup!(name: "Joseph"), Longest);
+    assert_eq!(merged, tup!(name: "Joseph", farmer: "Ann"));
+}
+
+#[test]
+#[tup_default]
+fn merge_defaults() {
+    let defaults: Tup!(cows: i32 = 1, bulls: i32 = 2) = tup!().into_tup();
+
+    // A set argument takes precedence over a default on either side.
+    let merged: Tup!(cows: i32, bulls: i32) = (defaults + tup!(cows: 5)).into_tup();
+    assert_eq!(merged, tup!(cows: 5, bulls: 2));
+    let merged: Tup!(cows: i32, bulls: i32) = tup!(cows: 5).merge_left(defaults).into_tup();
+    assert_eq!(merged, tup!(cows: 5, bulls: 2));
+    let merged: Tup!(cows: i32, bulls: i32) = (tup!(cows: 5) + defaults).into_tup();
+    assert_eq!(merged, tup!(cows: 5, bulls: 2));
+}
+
+#[test]
+#[tup_default]
+fn merge_strips_validators() {
+    let validated: Tup!(cows: i32 where cows > 0) = tup!(cows: 1).try_into_tup().unwrap();
+
+    // The merged tup is no longer validated, so it converts with `into_tup`.
+    let merged: Tup!(cows: i32, bulls: i32) = (validated + tup!(bulls: 2)).into_tup();
+    assert_eq!(merged, tup!(cows: 1, bulls: 2));
+    let merged: Tup!(cows: i32) = validated.merge_left(tup!(cows: -1)).into_tup();
+    assert_eq!(merged, tup!(cows: 1));
+
+    // Converting back into the validated tup runs the validator on the merged value again.
+    let invalid = (validated + tup!(cows: -1)).try_into_tup().map(count);
+    assert_eq!(invalid.unwrap_err().argument(), "cows");
+}
+
+#[tup_default]
+fn count(farm: Tup!(cows: i32 where cows > 0)) -> i32 {
+    farm.cows
+}
+
+#[test]
+fn subtract() {
+    let farm = tup!(cows: 1, bulls: 2, hens: 3);
+    assert_eq!(farm - tup!(bulls: ()), tup!(cows: 1, hens: 3));
+    assert_eq!(farm - tup!(bulls: 0, hens: "any"), tup!(cows: 1));
+
+    // Removing arguments the tup does not contain leaves it unchanged.
+    assert_eq!(farm - tup!(dogs: ()), farm);
+    assert_eq!(farm - tup!(), farm);
+    assert_eq!(farm - farm, tup!());
+}
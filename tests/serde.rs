@@ -0,0 +1,97 @@
+#![cfg(feature = "serde")]
+
+use named_tup::{tup, tup_default, tup_type, Tup, TupInto};
+
+tup_type!(Config = Tup!(host: String, port: u16 = 80, retries: u32 = 3));
+tup_type!(Size = Tup!(width: i32 = 1, height: i32 = width * 2));
+
+fn parse(json: &str) -> serde_json::Result<Config> {
+    serde_json::from_str(json)
+}
+
+fn parse_size(json: &str) -> serde_json::Result<Size> {
+    serde_json::from_str(json)
+}
+
+#[test]
+fn round_trip() {
+    let tup = tup!(host: "localhost".to_string(), port: 8080u16);
+    let json = serde_json::to_string(&tup).unwrap();
+    assert_eq!(json, r#"{"host":"localhost","port":8080}"#);
+    assert_eq!(
+        serde_json::from_str::<Tup!(host: String, port: u16)>(&json).unwrap(),
+        tup
+    );
+
+    assert_eq!(serde_json::to_string(&tup!()).unwrap(), "{}");
+    assert_eq!(serde_json::from_str::<Tup!()>("{}").unwrap(), tup!());
+}
+
+#[test]
+#[tup_default]
+fn defaults_are_filled() {
+    let config: Tup!(host: String, port: u16, retries: u32) =
+        parse(r#"{"host":"example.com"}"#).unwrap().into_tup();
+    assert_eq!(
+        config,
+        tup!(host: "example.com".to_string(), port: 80, retries: 3)
+    );
+
+    let config: Tup!(host: String, port: u16, retries: u32) =
+        parse(r#"{"retries":0,"host":"example.com","port":443}"#)
+            .unwrap()
+            .into_tup();
+    assert_eq!(
+        config,
+        tup!(host: "example.com".to_string(), port: 443, retries: 0)
+    );
+
+    // A defaulted argument is serialised with its value.
+    let json = serde_json::to_string(&parse(r#"{"host":"a"}"#).unwrap()).unwrap();
+    assert_eq!(json, r#"{"host":"a","port":80,"retries":3}"#);
+}
+
+#[test]
+#[tup_default]
+fn dependent_defaults_are_filled() {
+    let size: Tup!(width: i32, height: i32) = parse_size("{}").unwrap().into_tup();
+    assert_eq!(size, tup!(width: 1, height: 2));
+
+    let size: Tup!(width: i32, height: i32) = parse_size(r#"{"width":3}"#).unwrap().into_tup();
+    assert_eq!(size, tup!(width: 3, height: 6));
+
+    let size: Tup!(width: i32, height: i32) = parse_size(r#"{"height":5}"#).unwrap().into_tup();
+    assert_eq!(size, tup!(width: 1, height: 5));
+}
+
+#[test]
+fn unknown_arguments_are_ignored() {
+    let config = parse(r#"{"host":"a","cows":3,"path":"/"}"#).unwrap();
+    assert_eq!(config.host, "a");
+}
+
+#[test]
+fn invalid_maps_are_rejected() {
+    let err = parse(r#"{"port":80}"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `host`"), "{err}");
+
+    let err = parse(r#"{"host":"a","host":"b"}"#).unwrap_err();
+    assert!(err.to_string().contains("duplicate field `host`"), "{err}");
+
+    let err = parse(r#"{"host":"a","port":-1}"#).unwrap_err();
+    assert!(err.is_data(), "{err}");
+
+    let err = parse(r#"["a"]"#).unwrap_err();
+    assert!(err.to_string().contains("a map of tup arguments"), "{err}");
+}
+
+#[test]
+#[tup_default]
+fn validators_reject_values() {
+    let count = serde_json::from_str::<Tup!(count: u32 where count > 0)>(r#"{"count":1}"#);
+    assert_eq!(count.unwrap().count, 1);
+
+    let err = serde_json::from_str::<Tup!(count: u32 where count > 0)>(r#"{"count":0}"#);
+    let err = err.unwrap_err().to_string();
+    assert!(err.contains("the tup argument `count` is invalid"), "{err}");
+}